@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rbt3::hierarchy::{propagate, propagate_segmented};
+use rbt3::{Quat, Rbt, Vec3};
+use std::hint::black_box;
+
+/// The number of hierarchies.
+const HIERARCHIES: usize = 64;
+/// The number of nodes per hierarchy.
+const NODES: usize = 256;
+
+/// Returns the parents and locals of one hierarchy, a binary tree in breadth first order.
+fn hierarchy() -> (Vec<Option<usize>>, Vec<Rbt>) {
+    let parents = (0..NODES)
+        .map(|i| i.checked_sub(1).map(|i| i / 2))
+        .collect();
+    let locals = (0..NODES)
+        .map(|i| {
+            Rbt::from_t_r(
+                Vec3::new(0.1, i as f32 * 0.01, 0.0),
+                Quat::from_rotation_z(i as f32 * 0.001),
+            )
+        })
+        .collect();
+    (parents, locals)
+}
+
+fn bench(c: &mut Criterion) {
+    let (parents, locals) = hierarchy();
+    let total = HIERARCHIES * NODES;
+    let mut out = vec![Rbt::new(); total];
+
+    // All hierarchies in one scan, with parent indices offset into the combined arrays.
+    let flat_parents: Vec<Option<usize>> = (0..HIERARCHIES)
+        .flat_map(|k| parents.iter().map(move |p| p.map(|p| k * NODES + p)))
+        .collect();
+    let flat_locals: Vec<Rbt> = (0..HIERARCHIES).flat_map(|_| locals.clone()).collect();
+    let _ = c.bench_function("propagate", |b| {
+        b.iter(|| propagate(black_box(&flat_parents), black_box(&flat_locals), &mut out))
+    });
+
+    let lengths = vec![NODES; HIERARCHIES];
+    let bases: Vec<Rbt> = (0..HIERARCHIES)
+        .map(|k| Rbt::from_t(Vec3::new(k as f32, 0.0, 0.0)))
+        .collect();
+    let segment_parents: Vec<Option<usize>> =
+        (0..HIERARCHIES).flat_map(|_| parents.clone()).collect();
+    let _ = c.bench_function("propagate_segmented", |b| {
+        b.iter(|| {
+            propagate_segmented(
+                black_box(&lengths),
+                black_box(&bases),
+                black_box(&segment_parents),
+                black_box(&flat_locals),
+                &mut out,
+            )
+        })
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+//! Decodes arbitrary bytes, and checks that whatever decodes re-encodes to the bytes it was decoded from,
+//! up to rotation normalization.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rbt3::binary::{self, RBT_SIZE, STAMPED_RBT_SIZE};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok((rbt, rest)) = binary::decode_rbt(data) {
+        assert_eq!(rest, &data[RBT_SIZE..]);
+        let mut buf = [0; RBT_SIZE];
+        let encoded = binary::encode_rbt(&rbt, &mut buf).unwrap();
+        assert_eq!(encoded[..12], data[..12]);
+    }
+    if let Ok((stamped, rest)) = binary::decode_stamped(data) {
+        assert_eq!(rest, &data[STAMPED_RBT_SIZE..]);
+        let mut buf = [0; STAMPED_RBT_SIZE];
+        let encoded = binary::encode_stamped(&stamped, &mut buf).unwrap();
+        assert_eq!(encoded[..20], data[..20]);
+    }
+});
@@ -0,0 +1,66 @@
+//! Axis-aligned bounding boxes.
+
+use crate::{Mat3, Rbt, Vec3};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// An axis-aligned bounding box.
+pub struct Aabb {
+    /// The minimum corner.
+    pub min: Vec3,
+    /// The maximum corner.
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// Creates an AABB from minimum and maximum corners.
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Aabb { min, max }
+    }
+
+    /// Creates an AABB from center and half extents.
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Self {
+        Aabb {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    /// Returns the center.
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the half extents.
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Returns whether `point` is inside or on the boundary.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// Returns whether `self` and `other` overlap.
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    /// Returns the smallest AABB containing both `self` and `other`.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+}
+
+impl Rbt {
+    /// Returns the tightest AABB containing `aabb` transformed by `self`.
+    pub fn transform_aabb(&self, aabb: &Aabb) -> Aabb {
+        let abs_r = Mat3::from_quat(self.rotation).abs();
+        Aabb::from_center_half_extents(
+            self.rotation * aabb.center() + self.translation,
+            abs_r * aabb.half_extents(),
+        )
+    }
+}
@@ -0,0 +1,126 @@
+//! Interop with general affine transforms.
+
+use crate::{Affine3A, Mat3, Mat4, Quat, Rbt, Vec3};
+use core::ops::Mul;
+
+impl Rbt {
+    /// Converts to an `Affine3A`.
+    pub fn to_affine3a(&self) -> Affine3A {
+        Affine3A::from_rotation_translation(self.rotation, self.translation)
+    }
+
+    /// Converts from an `Affine3A`.
+    ///
+    /// Won't check for rigidness.
+    pub fn from_affine3a(affine: &Affine3A) -> Self {
+        Rbt {
+            translation: affine.translation.into(),
+            rotation: Quat::from_mat3a(&affine.matrix3),
+        }
+    }
+
+    /// Splits a 4x4 affine matrix into rigid part, scale and shear.
+    ///
+    /// Returns `(rbt, scale, shear)` such that
+    /// `mat4 == rbt.to_mat4() * Mat4::from_scale(scale) * Mat4::from_mat3(shear)`,
+    /// where `shear` is upper triangular with unit diagonal.
+    /// A reflection shows up as a negative `scale.z`. The projective row of `mat4` is ignored.
+    pub fn decompose_mat4(mat4: &Mat4) -> (Rbt, Vec3, Mat3) {
+        let m = Mat3::from_mat4(*mat4);
+        // Gram-Schmidt, completing the basis if columns are degenerate.
+        let q0 = m.x_axis.try_normalize().unwrap_or(Vec3::X);
+        let q1 = (m.y_axis - q0 * q0.dot(m.y_axis))
+            .try_normalize()
+            .unwrap_or_else(|| q0.any_orthonormal_vector());
+        let r = Mat3::from_cols(q0, q1, q0.cross(q1));
+        // Upper triangular.
+        let k = r.transpose() * m;
+        let scale = k.diagonal();
+        let inv_scale = Vec3::select(scale.cmpeq(Vec3::ZERO), Vec3::ONE, scale.recip());
+        let shear = Mat3::from_diagonal(inv_scale) * k;
+        (
+            Rbt {
+                translation: mat4.w_axis.truncate(),
+                rotation: Quat::from_mat3(&r).normalize(),
+            },
+            scale,
+            shear,
+        )
+    }
+}
+
+impl From<Rbt> for Affine3A {
+    fn from(rbt: Rbt) -> Self {
+        rbt.to_affine3a()
+    }
+}
+
+impl From<Rbt> for Mat4 {
+    fn from(rbt: Rbt) -> Self {
+        rbt.to_mat4()
+    }
+}
+
+impl Mul<Affine3A> for &Rbt {
+    type Output = Affine3A;
+
+    fn mul(self, rhs: Affine3A) -> Affine3A {
+        self.to_affine3a() * rhs
+    }
+}
+
+impl Mul<Affine3A> for Rbt {
+    type Output = Affine3A;
+
+    fn mul(self, rhs: Affine3A) -> Affine3A {
+        &self * rhs
+    }
+}
+
+impl Mul<&Rbt> for Affine3A {
+    type Output = Affine3A;
+
+    fn mul(self, rhs: &Rbt) -> Affine3A {
+        self * rhs.to_affine3a()
+    }
+}
+
+impl Mul<Rbt> for Affine3A {
+    type Output = Affine3A;
+
+    fn mul(self, rhs: Rbt) -> Affine3A {
+        self * &rhs
+    }
+}
+
+impl Mul<Mat4> for &Rbt {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        self.to_mat4() * rhs
+    }
+}
+
+impl Mul<Mat4> for Rbt {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        &self * rhs
+    }
+}
+
+impl Mul<&Rbt> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: &Rbt) -> Mat4 {
+        self * rhs.to_mat4()
+    }
+}
+
+impl Mul<Rbt> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Rbt) -> Mat4 {
+        self * &rhs
+    }
+}
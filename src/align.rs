@@ -0,0 +1,137 @@
+//! Rigid alignment of point correspondences.
+
+use crate::{linalg, rng::Rng, Quat, Rbt, Vec3};
+
+/// Returns the transform `t` minimizing the sum of `|t * src[i] - dst[i]|²`.
+///
+/// Uses Horn's closed form quaternion method.
+/// Returns `None` if the slices have different lengths or fewer than 3 points.
+/// The result is arbitrary if the points are collinear.
+pub fn fit_rigid(src: &[Vec3], dst: &[Vec3]) -> Option<Rbt> {
+    if src.len() != dst.len() || src.len() < 3 {
+        return None;
+    }
+    let n = src.len() as f32;
+    let cs = src.iter().sum::<Vec3>() / n;
+    let cd = dst.iter().sum::<Vec3>() / n;
+
+    let mut s = [[0.0f64; 3]; 3];
+    for (a, b) in src.iter().zip(dst) {
+        let (a, b) = ((*a - cs).to_array(), (*b - cd).to_array());
+        for i in 0..3 {
+            for j in 0..3 {
+                s[i][j] += a[i] as f64 * b[j] as f64;
+            }
+        }
+    }
+    let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+    let n = [
+        [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+        [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+        [szx - sxz, sxy + syx, -sxx + syy - szz, syz + szy],
+        [sxy - syx, szx + sxz, syz + szy, -sxx - syy + szz],
+    ];
+    let (values, vectors) = linalg::symmetric_eigen(&n);
+    let mut best = 0;
+    for i in 1..4 {
+        if values[i] > values[best] {
+            best = i;
+        }
+    }
+    let q = Quat::from_xyzw(
+        vectors[1][best] as f32,
+        vectors[2][best] as f32,
+        vectors[3][best] as f32,
+        vectors[0][best] as f32,
+    )
+    .normalize();
+    Some(Rbt::from_t_r(cd - q * cs, q))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Settings of a RANSAC rigid alignment.
+pub struct Ransac {
+    /// Correspondences with residual distance below this are inliers.
+    pub threshold: f32,
+    /// The number of random minimal samples to try.
+    pub max_iterations: usize,
+    /// The seed of the random sampling. Same seeds give same results.
+    pub seed: u64,
+}
+
+impl Default for Ransac {
+    fn default() -> Self {
+        Ransac {
+            threshold: 0.01,
+            max_iterations: 1000,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of a RANSAC rigid alignment.
+pub struct RansacFit {
+    /// The transform refit on all inliers.
+    pub rbt: Rbt,
+    /// Whether each correspondence is an inlier of `rbt`.
+    pub inliers: Vec<bool>,
+}
+
+impl Ransac {
+    /// Robustly fits the transform mapping `src` to `dst`, like `fit_rigid`.
+    ///
+    /// Returns `None` if `fit_rigid` would, or no sample gives at least 3 inliers.
+    pub fn fit(&self, src: &[Vec3], dst: &[Vec3]) -> Option<RansacFit> {
+        if src.len() != dst.len() || src.len() < 3 {
+            return None;
+        }
+        let len = src.len();
+        let mut rng = Rng::new(self.seed);
+        let threshold2 = self.threshold * self.threshold;
+        let inliers_of = |rbt: &Rbt| -> Vec<bool> {
+            src.iter()
+                .zip(dst)
+                .map(|(a, b)| {
+                    (rbt.rotation * *a + rbt.translation).distance_squared(*b) < threshold2
+                })
+                .collect()
+        };
+
+        let mut best: Option<(usize, Rbt)> = None;
+        for _ in 0..self.max_iterations {
+            let i = rng.below(len);
+            let j = rng.below(len);
+            let k = rng.below(len);
+            if i == j || j == k || i == k {
+                continue;
+            }
+            let Some(rbt) = fit_rigid(&[src[i], src[j], src[k]], &[dst[i], dst[j], dst[k]]) else {
+                continue;
+            };
+            let count = inliers_of(&rbt).iter().filter(|x| **x).count();
+            if best.as_ref().is_none_or(|(c, _)| count > *c) {
+                best = Some((count, rbt));
+                if count == len {
+                    break;
+                }
+            }
+        }
+
+        let (count, rbt) = best?;
+        if count < 3 {
+            return None;
+        }
+        let inliers = inliers_of(&rbt);
+        let (s, d): (Vec<Vec3>, Vec<Vec3>) = src
+            .iter()
+            .zip(dst)
+            .zip(&inliers)
+            .filter(|(_, inlier)| **inlier)
+            .map(|((a, b), _)| (*a, *b))
+            .unzip();
+        let rbt = fit_rigid(&s, &d)?;
+        let inliers = inliers_of(&rbt);
+        Some(RansacFit { rbt, inliers })
+    }
+}
@@ -0,0 +1,110 @@
+//! 16-byte aligned rigid body transform.
+
+use crate::{Quat, Rbt, Vec3A};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[repr(C, align(16))]
+/// A rigid body transform with `Vec3A` translation, so composition and point transforms use SIMD.
+///
+/// 32 bytes, 16-byte aligned.
+pub struct RbtA {
+    /// The translation part.
+    pub translation: Vec3A,
+    /// The rotation part.
+    pub rotation: Quat,
+}
+
+impl RbtA {
+    /// Returns the identity transform.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a RbtA from translation and rotation.
+    pub fn from_t_r(translation: Vec3A, rotation: Quat) -> Self {
+        RbtA {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Converts from a `Rbt`.
+    pub fn from_rbt(rbt: &Rbt) -> Self {
+        RbtA {
+            translation: rbt.translation.into(),
+            rotation: rbt.rotation,
+        }
+    }
+
+    /// Converts to a `Rbt`.
+    pub fn to_rbt(&self) -> Rbt {
+        Rbt::from_t_r(self.translation.into(), self.rotation)
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let inv_r = self.rotation.conjugate();
+        RbtA {
+            translation: -(inv_r * self.translation),
+            rotation: inv_r,
+        }
+    }
+
+    /// Transforms `point`.
+    pub fn transform_point(&self, point: Vec3A) -> Vec3A {
+        self.rotation * point + self.translation
+    }
+
+    /// Transforms `vector`, ignoring translation.
+    pub fn transform_vector(&self, vector: Vec3A) -> Vec3A {
+        self.rotation * vector
+    }
+}
+
+impl From<Rbt> for RbtA {
+    fn from(rbt: Rbt) -> Self {
+        RbtA::from_rbt(&rbt)
+    }
+}
+
+impl From<RbtA> for Rbt {
+    fn from(rbt: RbtA) -> Self {
+        rbt.to_rbt()
+    }
+}
+
+impl Mul<&RbtA> for &RbtA {
+    type Output = RbtA;
+
+    fn mul(self, rhs: &RbtA) -> RbtA {
+        RbtA {
+            translation: self.rotation * rhs.translation + self.translation,
+            rotation: (self.rotation * rhs.rotation).normalize(),
+        }
+    }
+}
+
+impl Mul<RbtA> for &RbtA {
+    type Output = RbtA;
+
+    fn mul(self, rhs: RbtA) -> RbtA {
+        self * &rhs
+    }
+}
+
+impl Mul<&RbtA> for RbtA {
+    type Output = RbtA;
+
+    fn mul(self, rhs: &RbtA) -> RbtA {
+        &self * rhs
+    }
+}
+
+impl Mul<RbtA> for RbtA {
+    type Output = RbtA;
+
+    fn mul(self, rhs: RbtA) -> RbtA {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,96 @@
+//! Batch transforms.
+
+use crate::{Mat3, Rbt, Vec3};
+
+impl Rbt {
+    /// Transforms `points` in place.
+    ///
+    /// The rotation is converted to a matrix once for the whole slice.
+    pub fn transform_points_in_place(&self, points: &mut [Vec3]) {
+        let r = Mat3::from_quat(self.rotation);
+        for p in points {
+            *p = r * *p + self.translation;
+        }
+    }
+
+    /// Transforms `points` in place by the inverse of `self`.
+    ///
+    /// The rotation is converted to a matrix once for the whole slice.
+    pub fn inverse_transform_points_in_place(&self, points: &mut [Vec3]) {
+        let inv_r = Mat3::from_quat(self.rotation).transpose();
+        for p in points {
+            *p = inv_r * (*p - self.translation);
+        }
+    }
+
+    /// Transforms vertex positions and, if given, normals in place, each in its own `f32` buffer.
+    ///
+    /// Both buffers share a layout: each vertex occupies `stride` floats, with its attribute as 3 floats at `offset`.
+    /// Normals are only rotated. A buffer may omit padding after its last vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attribute doesn't fit in `stride`.
+    pub fn transform_vertices(
+        &self,
+        positions: &mut [f32],
+        normals: Option<&mut [f32]>,
+        stride: usize,
+        offset: usize,
+    ) {
+        assert!(offset + 3 <= stride, "attribute exceeds stride");
+        let r = Mat3::from_quat(self.rotation);
+        map_attribute(positions, stride, offset, |p| r * p + self.translation);
+        if let Some(normals) = normals {
+            map_attribute(normals, stride, offset, |n| r * n);
+        }
+    }
+
+    /// Transforms vertices in a single interleaved `f32` buffer in place.
+    ///
+    /// Each vertex occupies `stride` floats. Its position is 3 floats at `position_offset`,
+    /// and if `normal_offset` is given, its normal is 3 floats at that offset, which is only rotated.
+    /// The buffer may omit padding after the last vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an attribute doesn't fit in `stride`.
+    pub fn transform_interleaved_vertices(
+        &self,
+        vertices: &mut [f32],
+        stride: usize,
+        position_offset: usize,
+        normal_offset: Option<usize>,
+    ) {
+        assert!(position_offset + 3 <= stride, "position exceeds stride");
+        let mut end = position_offset + 3;
+        if let Some(normal_offset) = normal_offset {
+            assert!(normal_offset + 3 <= stride, "normal exceeds stride");
+            end = end.max(normal_offset + 3);
+        }
+
+        let r = Mat3::from_quat(self.rotation);
+        let mut start = 0;
+        while start + end <= vertices.len() {
+            let vertex = &mut vertices[start..start + end];
+            let p = &mut vertex[position_offset..position_offset + 3];
+            let q = r * Vec3::from_slice(p) + self.translation;
+            q.write_to_slice(p);
+            if let Some(normal_offset) = normal_offset {
+                let n = &mut vertex[normal_offset..normal_offset + 3];
+                (r * Vec3::from_slice(n)).write_to_slice(n);
+            }
+            start += stride;
+        }
+    }
+}
+
+/// Replaces each 3-float attribute at `offset` of every `stride` floats in `buffer` with `f` of it.
+fn map_attribute(buffer: &mut [f32], stride: usize, offset: usize, f: impl Fn(Vec3) -> Vec3) {
+    let mut start = offset;
+    while start + 3 <= buffer.len() {
+        let attribute = &mut buffer[start..start + 3];
+        f(Vec3::from_slice(attribute)).write_to_slice(attribute);
+        start += stride;
+    }
+}
@@ -0,0 +1,170 @@
+//! Compact binary encoding with postcard, for telemetry links.
+//!
+//! The encoding is stable across versions of this crate:
+//!
+//! - `Rbt`: 28 bytes, the `f32`s `tx, ty, tz, qx, qy, qz, qw`, each little endian.
+//! - `StampedRbt`: 36 bytes, the time as a little endian `f64`, then the `Rbt`.
+//!
+//! Decoding normalizes the rotation; everything else round-trips bit for bit.
+//! There's no framing or checksum; add those at the link layer.
+//!
+//! The decoders are fuzzed by the `cargo fuzz` target in `fuzz/`.
+
+use crate::{Rbt, StampedRbt};
+
+/// The encoded size of a `Rbt`.
+pub const RBT_SIZE: usize = 28;
+
+/// The encoded size of a `StampedRbt`.
+pub const STAMPED_RBT_SIZE: usize = 36;
+
+/// Encodes `rbt` into the start of `buf`, returning the used part.
+pub fn encode_rbt<'a>(rbt: &Rbt, buf: &'a mut [u8]) -> Result<&'a mut [u8], postcard::Error> {
+    postcard::to_slice(rbt, buf)
+}
+
+/// Decodes a `Rbt` from the start of `buf`, returning it and the remaining bytes.
+pub fn decode_rbt(buf: &[u8]) -> Result<(Rbt, &[u8]), postcard::Error> {
+    postcard::take_from_bytes(buf)
+}
+
+/// Encodes `stamped` into the start of `buf`, returning the used part.
+pub fn encode_stamped<'a>(
+    stamped: &StampedRbt,
+    buf: &'a mut [u8],
+) -> Result<&'a mut [u8], postcard::Error> {
+    postcard::to_slice(stamped, buf)
+}
+
+/// Decodes a `StampedRbt` from the start of `buf`, returning it and the remaining bytes.
+pub fn decode_stamped(buf: &[u8]) -> Result<(StampedRbt, &[u8]), postcard::Error> {
+    postcard::take_from_bytes(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quat, Vec3};
+
+    fn bits(rbt: &Rbt) -> [u32; 7] {
+        let (t, r) = (rbt.translation, rbt.rotation);
+        [t.x, t.y, t.z, r.x, r.y, r.z, r.w].map(f32::to_bits)
+    }
+
+    /// Returns `rbt` as decoding is expected to produce it.
+    fn decoded(rbt: &Rbt) -> Rbt {
+        Rbt::from_t_r(rbt.translation, rbt.rotation.normalize())
+    }
+
+    /// Returns a pseudo-random bit pattern, which may be a NaN, an infinity or a subnormal.
+    fn random_f32(seed: &mut u64) -> f32 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        f32::from_bits((*seed >> 32) as u32)
+    }
+
+    fn edge_rbts() -> impl Iterator<Item = Rbt> {
+        let values = [
+            0.0,
+            -0.0,
+            f32::MIN_POSITIVE / 2.0,
+            -f32::from_bits(1),
+            f32::MAX,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        let random: [Rbt; 64] = core::array::from_fn(|_| {
+            let v: [f32; 7] = core::array::from_fn(|_| random_f32(&mut seed));
+            Rbt::from_t_r(
+                Vec3::new(v[0], v[1], v[2]),
+                Quat::from_xyzw(v[3], v[4], v[5], v[6]),
+            )
+        });
+        values
+            .into_iter()
+            .map(|x| Rbt::from_t_r(Vec3::splat(x), Quat::from_xyzw(x, 0.5, -0.5, 0.5)))
+            .chain(random)
+    }
+
+    #[test]
+    fn rbt_golden_bytes() {
+        let rbt = Rbt::from_t_r(
+            Vec3::new(1.0, -2.0, 0.5),
+            Quat::from_xyzw(0.5, -0.5, 0.5, 0.5),
+        );
+        let mut buf = [0; RBT_SIZE];
+        let expected = [
+            0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x3f, //
+            0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0xbf, 0x00, 0x00, 0x00, 0x3f, //
+            0x00, 0x00, 0x00, 0x3f,
+        ];
+        assert_eq!(encode_rbt(&rbt, &mut buf).unwrap(), expected);
+        let (decoded, rest) = decode_rbt(&expected).unwrap();
+        assert_eq!(decoded, rbt);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn stamped_golden_bytes() {
+        let stamped = StampedRbt::new(
+            1.5,
+            Rbt::from_t_r(Vec3::new(1.0, -2.0, 0.5), Quat::IDENTITY),
+        );
+        let mut buf = [0; STAMPED_RBT_SIZE];
+        let expected = [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, //
+            0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x3f, //
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x80, 0x3f,
+        ];
+        assert_eq!(encode_stamped(&stamped, &mut buf).unwrap(), expected);
+        let (decoded, rest) = decode_stamped(&expected).unwrap();
+        assert_eq!(decoded, stamped);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn round_trips_edge_values() {
+        let times = [
+            0.0,
+            -0.0,
+            f64::MIN_POSITIVE / 2.0,
+            f64::INFINITY,
+            f64::NAN,
+            1e9,
+        ];
+        for (i, rbt) in edge_rbts().enumerate() {
+            let mut buf = [0; STAMPED_RBT_SIZE + 1];
+            assert_eq!(encode_rbt(&rbt, &mut buf).unwrap().len(), RBT_SIZE);
+            let (decoded_rbt, rest) = decode_rbt(&buf).unwrap();
+            assert_eq!(bits(&decoded_rbt), bits(&decoded(&rbt)), "{rbt:?}");
+            assert_eq!(rest.len(), buf.len() - RBT_SIZE);
+
+            let stamped = StampedRbt::new(times[i % times.len()], rbt.clone());
+            assert_eq!(
+                encode_stamped(&stamped, &mut buf).unwrap().len(),
+                STAMPED_RBT_SIZE
+            );
+            let (decoded_stamped, rest) = decode_stamped(&buf).unwrap();
+            assert_eq!(decoded_stamped.time.to_bits(), stamped.time.to_bits());
+            assert_eq!(bits(&decoded_stamped.rbt), bits(&decoded(&rbt)), "{rbt:?}");
+            assert_eq!(rest.len(), 1);
+        }
+    }
+
+    #[test]
+    fn rejects_short_buffers() {
+        let mut buf = [0; STAMPED_RBT_SIZE];
+        let _ = encode_stamped(&StampedRbt::new(0.0, Rbt::new()), &mut buf).unwrap();
+        for len in 0..RBT_SIZE {
+            assert!(decode_rbt(&buf[..len]).is_err());
+            assert!(encode_rbt(&Rbt::new(), &mut buf[..len]).is_err());
+        }
+        for len in 0..STAMPED_RBT_SIZE {
+            assert!(decode_stamped(&buf[..len]).is_err());
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Camera controllers.
+//!
+//! Cameras look along their local -Z axis with local +Y up, and world up is +Y.
+
+use crate::{math, Quat, Rbt, Vec2, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A camera orbiting around a target point.
+pub struct OrbitCamera {
+    /// The point the camera looks at.
+    pub target: Vec3,
+    /// The distance from the camera to `target`.
+    pub distance: f32,
+    /// The orientation of the camera.
+    pub orientation: Quat,
+}
+
+impl Default for OrbitCamera {
+    fn default() -> Self {
+        OrbitCamera {
+            target: Vec3::ZERO,
+            distance: 1.0,
+            orientation: Quat::IDENTITY,
+        }
+    }
+}
+
+impl OrbitCamera {
+    /// Creates a camera at `distance` from `target`, with given orientation.
+    pub fn new(target: Vec3, distance: f32, orientation: Quat) -> Self {
+        OrbitCamera {
+            target,
+            distance,
+            orientation,
+        }
+    }
+
+    /// Returns the camera-to-world transform.
+    pub fn pose(&self) -> Rbt {
+        Rbt::from_t_r(
+            self.target + self.orientation * Vec3::new(0.0, 0.0, self.distance),
+            self.orientation,
+        )
+    }
+
+    /// Rotates around the target by `dx` radians around world up and `dy` radians around camera right.
+    ///
+    /// Positive `dx` moves the camera to its left, positive `dy` moves the camera up.
+    pub fn rotate(&mut self, dx: f32, dy: f32) {
+        self.orientation =
+            (Quat::from_rotation_y(-dx) * self.orientation * Quat::from_rotation_x(-dy))
+                .normalize();
+    }
+
+    /// Moves the target by `dx` along camera right and `dy` along camera up, scaled by distance.
+    pub fn pan(&mut self, dx: f32, dy: f32) {
+        self.target += self.orientation * Vec3::new(dx, dy, 0.0) * self.distance;
+    }
+
+    /// Multiplies the distance by `factor`.
+    ///
+    /// `factor` less than 1 moves the camera closer to the target.
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance = (self.distance * factor).max(f32::EPSILON);
+    }
+
+    /// Rotates around the target with an arcball drag from `from` to `to`.
+    ///
+    /// Positions are in pixels with origin at the top left of a viewport of size `viewport`.
+    /// The scene follows the cursor over a virtual sphere filling the viewport.
+    pub fn arcball(&mut self, from: Vec2, to: Vec2, viewport: Vec2) {
+        let p0 = arcball_point(from, viewport);
+        let p1 = arcball_point(to, viewport);
+        let rotation = Quat::from_rotation_arc(p0, p1);
+        self.orientation = (self.orientation * rotation.inverse()).normalize();
+    }
+}
+
+/// Projects a pixel position onto the arcball sphere, returning a unit vector in camera space.
+///
+/// Points outside the sphere fall onto a hyperbolic sheet, so the mapping stays continuous.
+fn arcball_point(pixel: Vec2, viewport: Vec2) -> Vec3 {
+    let radius = viewport.min_element() * 0.5;
+    let x = (pixel.x - viewport.x * 0.5) / radius;
+    let y = (viewport.y * 0.5 - pixel.y) / radius;
+    let r2 = x * x + y * y;
+    let z = if r2 <= 0.5 {
+        math::sqrt(1.0 - r2)
+    } else {
+        0.5 / math::sqrt(r2)
+    };
+    Vec3::new(x, y, z).normalize()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A first-person camera driven by yaw and pitch.
+///
+/// The orientation is rebuilt from the angles every time, so it never picks up roll.
+pub struct FpsCamera {
+    /// The camera position.
+    pub position: Vec3,
+    /// Rotation around world up, in radians.
+    pub yaw: f32,
+    /// Rotation around camera right, in radians. Positive looks up.
+    pub pitch: f32,
+    /// The maximum absolute value of `pitch`.
+    pub max_pitch: f32,
+}
+
+impl Default for FpsCamera {
+    fn default() -> Self {
+        FpsCamera {
+            position: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            max_pitch: std::f32::consts::FRAC_PI_2 - 0.001,
+        }
+    }
+}
+
+impl FpsCamera {
+    /// Creates a camera at `position` looking along -Z.
+    pub fn new(position: Vec3) -> Self {
+        FpsCamera {
+            position,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the orientation of the camera.
+    pub fn orientation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw) * Quat::from_rotation_x(self.pitch)
+    }
+
+    /// Returns the camera-to-world transform.
+    pub fn pose(&self) -> Rbt {
+        Rbt::from_t_r(self.position, self.orientation())
+    }
+
+    /// Adds `dyaw` to yaw and `dpitch` to pitch, clamping pitch to `max_pitch`.
+    pub fn look(&mut self, dyaw: f32, dpitch: f32) {
+        self.yaw = math::wrap_angle(self.yaw + dyaw);
+        self.pitch = (self.pitch + dpitch).clamp(-self.max_pitch, self.max_pitch);
+    }
+
+    /// Moves by `delta` given in camera space.
+    pub fn move_local(&mut self, delta: Vec3) {
+        self.position += self.orientation() * delta;
+    }
+
+    /// Moves by `delta` given in camera space, ignoring pitch.
+    ///
+    /// Walking forward this way stays on the horizontal plane.
+    pub fn move_planar(&mut self, delta: Vec3) {
+        self.position += Quat::from_rotation_y(self.yaw) * delta;
+    }
+}
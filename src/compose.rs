@@ -0,0 +1,82 @@
+//! Rotation normalization, and composition with control over it.
+
+use crate::Rbt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// When to normalize the rotation while composing many transforms.
+pub enum NormalizePolicy {
+    /// Normalize after every multiplication, like `Mul`.
+    #[default]
+    Always,
+    /// Normalize after every `n` multiplications and once at the end. `0` behaves like `Never`.
+    EveryN(usize),
+    /// Never normalize.
+    Never,
+}
+
+impl Rbt {
+    /// Returns whether the rotation quaternion's length is within `eps` of 1.
+    ///
+    /// A quaternion of length `1 + δ` scales rotated vectors by about `1 + 2δ`,
+    /// so a point at distance `d` from the origin is transformed with an error of about `2δd`.
+    pub fn is_normalized(&self, eps: f32) -> bool {
+        (self.rotation.length() - 1.0).abs() <= eps
+    }
+
+    /// Returns `self` with the rotation normalized.
+    ///
+    /// Afterwards the quaternion's length is within a few `f32::EPSILON` of 1.
+    pub fn normalize(&self) -> Rbt {
+        Rbt {
+            translation: self.translation,
+            rotation: self.rotation.normalize(),
+        }
+    }
+
+    /// Normalizes the rotation in place.
+    pub fn normalize_in_place(&mut self) {
+        self.rotation = self.rotation.normalize();
+    }
+
+    /// Normalizes the rotation of every element of `rbts` in place.
+    pub fn renormalize_all(rbts: &mut [Rbt]) {
+        for rbt in rbts {
+            rbt.normalize_in_place();
+        }
+    }
+
+    /// Composes `self` and `rhs` like `self * rhs`, without normalizing the rotation.
+    pub fn mul_unnormalized(&self, rhs: &Rbt) -> Rbt {
+        Rbt {
+            translation: self.rotation * rhs.translation + self.translation,
+            rotation: self.rotation * rhs.rotation,
+        }
+    }
+
+    /// Composes `rbts` from left to right, normalizing according to `policy`.
+    ///
+    /// Returns the identity if `rbts` is empty.
+    pub fn compose_fast<'a>(
+        rbts: impl IntoIterator<Item = &'a Rbt>,
+        policy: NormalizePolicy,
+    ) -> Rbt {
+        let mut result = Rbt::new();
+        let mut count = 0;
+        for rbt in rbts {
+            result = result.mul_unnormalized(rbt);
+            count += 1;
+            let normalize = match policy {
+                NormalizePolicy::Always => true,
+                NormalizePolicy::EveryN(n) => n != 0 && count % n == 0,
+                NormalizePolicy::Never => false,
+            };
+            if normalize {
+                result.rotation = result.rotation.normalize();
+            }
+        }
+        if matches!(policy, NormalizePolicy::EveryN(n) if n != 0) {
+            result.rotation = result.rotation.normalize();
+        }
+        result
+    }
+}
@@ -0,0 +1,183 @@
+//! Constraints clamping poses and joint values to limits.
+//!
+//! The `clamp_*` functions modify their argument in place and return whether it was clamped.
+//! The constraint types return the constrained pose from `apply`.
+
+use crate::{math, Aabb, Quat, Rbt, Vec3};
+
+/// Limits the distance of the translation of `rbt` from `center` to `max_distance`.
+pub fn clamp_translation(rbt: &mut Rbt, center: Vec3, max_distance: f32) -> bool {
+    let offset = rbt.translation - center;
+    let distance = offset.length();
+    if distance <= max_distance {
+        return false;
+    }
+    rbt.translation = center + offset * (max_distance / distance);
+    true
+}
+
+/// Limits the angle between local `axis` of `rbt` and of `reference` to `max_angle` radians.
+///
+/// Rotates `rbt` by the smallest rotation that brings it back onto the cone, so twist around `axis` is kept.
+pub fn clamp_cone(rbt: &mut Rbt, reference: Quat, axis: Vec3, max_angle: f32) -> bool {
+    let a = reference * axis;
+    let b = rbt.rotation * axis;
+    let cross = a.cross(b);
+    let angle = math::atan2(cross.length(), a.dot(b));
+    if angle <= max_angle {
+        return false;
+    }
+    let n = cross
+        .try_normalize()
+        .unwrap_or_else(|| a.any_orthonormal_vector());
+    rbt.rotation = (Quat::from_axis_angle(n, max_angle - angle) * rbt.rotation).normalize();
+    true
+}
+
+/// Clamps each joint value `q[i]` to `[min[i], max[i]]`.
+///
+/// # Panics
+///
+/// Panics if the slices differ in length, or if `min[i] > max[i]` or either is NaN.
+pub fn clamp_joints(q: &mut [f32], min: &[f32], max: &[f32]) -> bool {
+    assert!(
+        q.len() == min.len() && q.len() == max.len(),
+        "length mismatch"
+    );
+    let mut clamped = false;
+    for ((q, min), max) in q.iter_mut().zip(min).zip(max) {
+        let c = q.clamp(*min, *max);
+        clamped |= c != *q;
+        *q = c;
+    }
+    clamped
+}
+
+/// Splits `rotation` into `(swing, twist)` with `rotation == swing * twist`,
+/// where `twist` rotates around unit `axis` and `swing` around an axis perpendicular to it.
+pub fn swing_twist(rotation: Quat, axis: Vec3) -> (Quat, Quat) {
+    let v = Vec3::new(rotation.x, rotation.y, rotation.z);
+    let p = axis * v.dot(axis);
+    let twist = Quat::from_xyzw(p.x, p.y, p.z, rotation.w);
+    let twist = if twist.length_squared() > f32::EPSILON {
+        twist.normalize()
+    } else {
+        // A half turn swing: any twist works.
+        Quat::IDENTITY
+    };
+    ((rotation * twist.conjugate()).normalize(), twist)
+}
+
+/// Returns the signed angle of `twist` around `axis`, in `[-π, π)`.
+fn twist_angle(twist: Quat, axis: Vec3) -> f32 {
+    let s = Vec3::new(twist.x, twist.y, twist.z).dot(axis);
+    math::wrap_angle(2.0 * math::atan2(s, twist.w))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Limits the swing of a local axis away from the same axis of a reference orientation.
+pub struct ConeLimit {
+    /// The reference orientation.
+    pub reference: Quat,
+    /// The local unit axis.
+    pub axis: Vec3,
+    /// The maximum angle between the axes, in radians.
+    pub max_angle: f32,
+}
+
+impl ConeLimit {
+    /// Returns `rbt` rotated by the smallest rotation bringing it inside the cone.
+    pub fn apply(&self, rbt: &Rbt) -> Rbt {
+        let mut result = rbt.clone();
+        let _ = clamp_cone(&mut result, self.reference, self.axis, self.max_angle);
+        result
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Limits the rotation relative to a reference orientation, with separate swing and twist limits.
+///
+/// The relative rotation is split into a twist around `axis` followed by a swing tilting `axis`, as in ragdoll joints.
+pub struct SwingTwistLimit {
+    /// The reference orientation.
+    pub reference: Quat,
+    /// The local unit twist axis.
+    pub axis: Vec3,
+    /// The maximum swing angle, in radians.
+    pub max_swing: f32,
+    /// The minimum twist angle, in radians.
+    pub min_twist: f32,
+    /// The maximum twist angle, in radians.
+    pub max_twist: f32,
+}
+
+impl SwingTwistLimit {
+    /// Returns `rbt` with its swing and twist clamped to the limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_twist > max_twist` or either is NaN.
+    pub fn apply(&self, rbt: &Rbt) -> Rbt {
+        let relative = self.reference.conjugate() * rbt.rotation;
+        let (swing, twist) = swing_twist(relative, self.axis);
+        let (swing_axis, swing_angle) = swing.to_axis_angle();
+        let swing = if swing_angle > self.max_swing {
+            Quat::from_axis_angle(swing_axis, self.max_swing)
+        } else {
+            swing
+        };
+        let angle = twist_angle(twist, self.axis).clamp(self.min_twist, self.max_twist);
+        let twist = Quat::from_axis_angle(self.axis, angle);
+        Rbt::from_t_r(
+            rbt.translation,
+            (self.reference * swing * twist).normalize(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A region translations are restricted to, in the coordinates of a frame.
+pub enum Region {
+    /// The plane through the frame origin with this unit normal.
+    Plane(Vec3),
+    /// The line through the frame origin with this unit direction.
+    Line(Vec3),
+    /// An axis-aligned box.
+    Box(Aabb),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Restricts the translation of a pose to a region given in another frame, e.g. the floor plane.
+pub struct TranslationConstraint {
+    /// The frame the region is given in.
+    pub frame: Rbt,
+    /// The region.
+    pub region: Region,
+    /// If set, this local axis of the pose is turned, by the smallest rotation, onto the plane normal,
+    /// the line direction, or the frame Z axis for boxes. Otherwise the rotation is untouched.
+    pub align_axis: Option<Vec3>,
+}
+
+impl TranslationConstraint {
+    /// Returns `rbt` with its translation projected onto the region.
+    pub fn apply(&self, rbt: &Rbt) -> Rbt {
+        let inverse = self.frame.inverse();
+        let local = inverse.rotation * rbt.translation + inverse.translation;
+        let (projected, target) = match &self.region {
+            Region::Plane(normal) => (local - *normal * local.dot(*normal), *normal),
+            Region::Line(direction) => (*direction * local.dot(*direction), *direction),
+            Region::Box(aabb) => (local.clamp(aabb.min, aabb.max), Vec3::Z),
+        };
+        let rotation = match self.align_axis {
+            Some(axis) => {
+                let target = self.frame.rotation * target;
+                (Quat::from_rotation_arc(rbt.rotation * axis, target) * rbt.rotation).normalize()
+            }
+            None => rbt.rotation,
+        };
+        Rbt::from_t_r(
+            self.frame.rotation * projected + self.frame.translation,
+            rotation,
+        )
+    }
+}
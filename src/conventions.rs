@@ -0,0 +1,198 @@
+//! Coordinate system conventions of common engines and tools, and of aerospace world and body frames.
+
+use crate::{Mat3, Quat, Rbt, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A coordinate system convention.
+///
+/// Described by where the semantic right, up and forward directions point, in the convention's own coordinates.
+pub struct Convention {
+    /// The right direction.
+    pub right: Vec3,
+    /// The up direction.
+    pub up: Vec3,
+    /// The forward direction.
+    pub forward: Vec3,
+}
+
+/// OpenGL: Y up, -Z forward, right handed.
+pub const OPENGL: Convention = Convention {
+    right: Vec3::X,
+    up: Vec3::Y,
+    forward: Vec3::NEG_Z,
+};
+
+/// Vulkan: Y down, Z forward, right handed.
+pub const VULKAN: Convention = Convention {
+    right: Vec3::X,
+    up: Vec3::NEG_Y,
+    forward: Vec3::Z,
+};
+
+/// Unity: Y up, Z forward, left handed.
+pub const UNITY: Convention = Convention {
+    right: Vec3::X,
+    up: Vec3::Y,
+    forward: Vec3::Z,
+};
+
+/// Unreal: Z up, X forward, Y right, left handed.
+pub const UNREAL: Convention = Convention {
+    right: Vec3::Y,
+    up: Vec3::Z,
+    forward: Vec3::X,
+};
+
+/// Blender and Z-up USD: Z up, Y forward, right handed.
+pub const BLENDER: Convention = Convention {
+    right: Vec3::X,
+    up: Vec3::Z,
+    forward: Vec3::Y,
+};
+
+impl Convention {
+    /// Returns whether this convention is right handed.
+    pub fn is_right_handed(&self) -> bool {
+        self.right.cross(self.up).dot(self.forward) < 0.0
+    }
+
+    /// Returns the matrix whose columns are right, up and forward.
+    fn basis(&self) -> Mat3 {
+        Mat3::from_cols(self.right, self.up, self.forward)
+    }
+}
+
+/// Returns the matrix that maps coordinates in convention `from` to coordinates in convention `to`.
+///
+/// Its determinant is -1 if the conventions differ in handedness.
+pub fn change_of_basis(from: &Convention, to: &Convention) -> Mat3 {
+    to.basis() * from.basis().transpose()
+}
+
+/// Converts a point or direction from convention `from` to convention `to`.
+pub fn convert_vec3(v: Vec3, from: &Convention, to: &Convention) -> Vec3 {
+    change_of_basis(from, to) * v
+}
+
+/// Converts a transform from convention `from` to convention `to`.
+///
+/// The rotation stays proper even when handedness flips.
+pub fn convert(rbt: &Rbt, from: &Convention, to: &Convention) -> Rbt {
+    let c = change_of_basis(from, to);
+    let rotation = c * Mat3::from_quat(rbt.rotation) * c.transpose();
+    Rbt {
+        translation: c * rbt.translation,
+        rotation: Quat::from_mat3(&rotation).normalize(),
+    }
+}
+
+/// The rotation mapping ENU world coordinates to NED, π around `(1, 1, 0) / √2`. It is its own inverse.
+const ENU_TO_NED: Quat = Quat::from_xyzw(
+    std::f32::consts::FRAC_1_SQRT_2,
+    std::f32::consts::FRAC_1_SQRT_2,
+    0.0,
+    0.0,
+);
+
+/// The rotation mapping FLU body coordinates to FRD, π around X. It is its own inverse.
+const FLU_TO_FRD: Quat = Quat::from_xyzw(1.0, 0.0, 0.0, 0.0);
+
+/// Converts world coordinates from east, north, up to north, east, down.
+pub fn enu_to_ned(v: Vec3) -> Vec3 {
+    Vec3::new(v.y, v.x, -v.z)
+}
+
+/// Converts world coordinates from north, east, down to east, north, up.
+pub fn ned_to_enu(v: Vec3) -> Vec3 {
+    Vec3::new(v.y, v.x, -v.z)
+}
+
+/// Converts body coordinates from forward, left, up to forward, right, down.
+pub fn flu_to_frd(v: Vec3) -> Vec3 {
+    Vec3::new(v.x, -v.y, -v.z)
+}
+
+/// Converts body coordinates from forward, right, down to forward, left, up.
+pub fn frd_to_flu(v: Vec3) -> Vec3 {
+    Vec3::new(v.x, -v.y, -v.z)
+}
+
+/// Converts a body-to-world orientation from FLU body in ENU world to FRD body in NED world.
+///
+/// E.g. level and facing east is identity in ENU/FLU and a yaw of +90° in NED/FRD.
+pub fn orientation_enu_flu_to_ned_frd(rotation: Quat) -> Quat {
+    (ENU_TO_NED * rotation * FLU_TO_FRD).normalize()
+}
+
+/// Converts a body-to-world orientation from FRD body in NED world to FLU body in ENU world.
+pub fn orientation_ned_frd_to_enu_flu(rotation: Quat) -> Quat {
+    (ENU_TO_NED * rotation * FLU_TO_FRD).normalize()
+}
+
+/// Converts a body-to-world pose from FLU body in ENU world to FRD body in NED world.
+pub fn pose_enu_flu_to_ned_frd(rbt: &Rbt) -> Rbt {
+    Rbt::from_t_r(
+        enu_to_ned(rbt.translation),
+        orientation_enu_flu_to_ned_frd(rbt.rotation),
+    )
+}
+
+/// Converts a body-to-world pose from FRD body in NED world to FLU body in ENU world.
+pub fn pose_ned_frd_to_enu_flu(rbt: &Rbt) -> Rbt {
+    Rbt::from_t_r(
+        ned_to_enu(rbt.translation),
+        orientation_ned_frd_to_enu_flu(rbt.rotation),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn pose() -> Rbt {
+        Rbt::from_t_r(
+            Vec3::new(1.0, -2.0, 3.0),
+            Quat::from_axis_angle(Vec3::new(0.3, -0.5, 0.8).normalize(), 1.2),
+        )
+    }
+
+    fn assert_close(a: Vec3, b: Vec3) {
+        assert!(a.abs_diff_eq(b, 1e-5), "{a} != {b}");
+    }
+
+    #[test]
+    fn round_trips_are_identity() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert_eq!(ned_to_enu(enu_to_ned(v)), v);
+        assert_eq!(frd_to_flu(flu_to_frd(v)), v);
+
+        let rbt = pose();
+        let back = pose_ned_frd_to_enu_flu(&pose_enu_flu_to_ned_frd(&rbt));
+        assert_close(back.translation, rbt.translation);
+        assert!(back.rotation.abs_diff_eq(rbt.rotation, 1e-5));
+    }
+
+    #[test]
+    fn level_east_is_yaw_90_in_ned() {
+        let rotation = orientation_enu_flu_to_ned_frd(Quat::IDENTITY);
+        let expected = Quat::from_rotation_z(FRAC_PI_2);
+        assert!(rotation.dot(expected).abs() > 1.0 - 1e-6, "{rotation}");
+    }
+
+    #[test]
+    fn body_axes_land_on_world_axes() {
+        let rbt = pose();
+        let ned = pose_enu_flu_to_ned_frd(&rbt);
+        // Forward stays forward, right is minus left, down is minus up.
+        assert_close(ned.rotation * Vec3::X, enu_to_ned(rbt.rotation * Vec3::X));
+        assert_close(ned.rotation * Vec3::Y, enu_to_ned(rbt.rotation * -Vec3::Y));
+        assert_close(ned.rotation * Vec3::Z, enu_to_ned(rbt.rotation * -Vec3::Z));
+
+        // Level and facing east: forward is east, right is south, down is down.
+        let level = orientation_enu_flu_to_ned_frd(Quat::IDENTITY);
+        assert_close(level * Vec3::X, Vec3::Y);
+        assert_close(level * Vec3::Y, -Vec3::X);
+        assert_close(level * Vec3::Z, Vec3::Z);
+    }
+}
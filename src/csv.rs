@@ -0,0 +1,328 @@
+//! CSV pose tables.
+
+use crate::{EulerRot, Mat3, Quat, Rbt, StampedRbt, Vec3};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The columns describing a pose.
+pub enum Layout {
+    /// `x, y, z, qx, qy, qz, qw`.
+    XyzQuat,
+    /// `x, y, z, a, b, c` with Euler angles in the given order, in degrees if `degrees` is set.
+    XyzEuler {
+        /// The rotation order.
+        order: EulerRot,
+        /// Whether angles are degrees rather than radians.
+        degrees: bool,
+    },
+    /// The top 3 rows of the 4x4 matrix, row by row: `r00, r01, r02, x, r10, ..., r22, z`.
+    Mat3x4,
+}
+
+impl Layout {
+    fn columns(&self) -> usize {
+        match self {
+            Layout::XyzQuat => 7,
+            Layout::XyzEuler { .. } => 6,
+            Layout::Mat3x4 => 12,
+        }
+    }
+
+    fn header(&self) -> &'static [&'static str] {
+        match self {
+            Layout::XyzQuat => &["x", "y", "z", "qx", "qy", "qz", "qw"],
+            Layout::XyzEuler { .. } => &["x", "y", "z", "a", "b", "c"],
+            Layout::Mat3x4 => &[
+                "r00", "r01", "r02", "x", "r10", "r11", "r12", "y", "r20", "r21", "r22", "z",
+            ],
+        }
+    }
+
+    fn parse(&self, v: &[f32]) -> Result<Rbt, &'static str> {
+        Ok(match *self {
+            Layout::XyzQuat => {
+                let q = Quat::from_xyzw(v[3], v[4], v[5], v[6]);
+                if q.length_squared() == 0.0 {
+                    return Err("rotation quaternion is zero");
+                }
+                Rbt::from_t_r(Vec3::new(v[0], v[1], v[2]), q.normalize())
+            }
+            Layout::XyzEuler { order, degrees } => {
+                let k = if degrees { 1f32.to_radians() } else { 1.0 };
+                Rbt::from_t_r(
+                    Vec3::new(v[0], v[1], v[2]),
+                    Quat::from_euler(order, v[3] * k, v[4] * k, v[5] * k),
+                )
+            }
+            Layout::Mat3x4 => {
+                let r = Mat3::from_cols(
+                    Vec3::new(v[0], v[4], v[8]),
+                    Vec3::new(v[1], v[5], v[9]),
+                    Vec3::new(v[2], v[6], v[10]),
+                );
+                Rbt::from_t_r(
+                    Vec3::new(v[3], v[7], v[11]),
+                    Quat::from_mat3(&r).normalize(),
+                )
+            }
+        })
+    }
+
+    fn format(&self, rbt: &Rbt) -> Vec<f32> {
+        let t = rbt.translation;
+        match *self {
+            Layout::XyzQuat => {
+                let q = rbt.rotation;
+                vec![t.x, t.y, t.z, q.x, q.y, q.z, q.w]
+            }
+            Layout::XyzEuler { order, degrees } => {
+                let k = if degrees { 1f32.to_degrees() } else { 1.0 };
+                let (a, b, c) = rbt.rotation.to_euler(order);
+                vec![t.x, t.y, t.z, a * k, b * k, c * k]
+            }
+            Layout::Mat3x4 => {
+                let r = Mat3::from_quat(rbt.rotation);
+                let (r0, r1, r2) = (r.row(0), r.row(1), r.row(2));
+                vec![
+                    r0.x, r0.y, r0.z, t.x, r1.x, r1.y, r1.z, t.y, r2.x, r2.y, r2.z, t.z,
+                ]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A CSV table format.
+pub struct CsvFormat {
+    /// The column separator. Any whitespace character means runs of whitespace.
+    pub delimiter: char,
+    /// The pose columns.
+    pub layout: Layout,
+    /// Whether a timestamp column comes first.
+    pub timestamp: bool,
+    /// Whether the first line is a header.
+    pub header: bool,
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        CsvFormat {
+            delimiter: ',',
+            layout: Layout::XyzQuat,
+            timestamp: true,
+            header: true,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// An error reading a CSV table.
+pub enum CsvError {
+    /// Reading failed.
+    Io(io::Error),
+    /// A line couldn't be parsed.
+    Parse {
+        /// The 1-based line number.
+        line: usize,
+        /// What went wrong.
+        message: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(f, "{}", e),
+            CsvError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(e) => Some(e),
+            CsvError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+impl CsvFormat {
+    /// Reads all poses.
+    ///
+    /// Empty lines and lines starting with `#` are skipped. A zero rotation quaternion is a parse error.
+    /// Without a timestamp column, poses are stamped with their row index.
+    pub fn read(&self, reader: impl BufRead) -> Result<Vec<StampedRbt>, CsvError> {
+        let expected = self.layout.columns() + self.timestamp as usize;
+        let mut poses = Vec::new();
+        let mut header = self.header;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if header {
+                header = false;
+                continue;
+            }
+            let fields: Vec<&str> = if self.delimiter.is_whitespace() {
+                trimmed.split_whitespace().collect()
+            } else {
+                trimmed.split(self.delimiter).map(str::trim).collect()
+            };
+            if fields.len() != expected {
+                return Err(CsvError::Parse {
+                    line: i + 1,
+                    message: format!("expected {} columns, found {}", expected, fields.len()),
+                });
+            }
+            let (time, fields) = if self.timestamp {
+                let time = fields[0].parse::<f64>().map_err(|e| CsvError::Parse {
+                    line: i + 1,
+                    message: format!("invalid timestamp {:?}: {}", fields[0], e),
+                })?;
+                (time, &fields[1..])
+            } else {
+                (poses.len() as f64, &fields[..])
+            };
+            let values = fields
+                .iter()
+                .map(|s| {
+                    s.parse::<f32>().map_err(|e| CsvError::Parse {
+                        line: i + 1,
+                        message: format!("invalid number {:?}: {}", s, e),
+                    })
+                })
+                .collect::<Result<Vec<f32>, CsvError>>()?;
+            let rbt = self
+                .layout
+                .parse(&values)
+                .map_err(|message| CsvError::Parse {
+                    line: i + 1,
+                    message: message.to_string(),
+                })?;
+            poses.push(StampedRbt::new(time, rbt));
+        }
+        Ok(poses)
+    }
+
+    /// Writes all poses.
+    pub fn write(&self, mut writer: impl Write, poses: &[StampedRbt]) -> io::Result<()> {
+        let delimiter = self.delimiter.to_string();
+        if self.header {
+            let mut names: Vec<&str> = Vec::new();
+            if self.timestamp {
+                names.push("time");
+            }
+            names.extend_from_slice(self.layout.header());
+            writeln!(writer, "{}", names.join(&delimiter))?;
+        }
+        for pose in poses {
+            let mut fields: Vec<String> = Vec::new();
+            if self.timestamp {
+                fields.push(pose.time.to_string());
+            }
+            fields.extend(self.layout.format(&pose.rbt).iter().map(f32::to_string));
+            writeln!(writer, "{}", fields.join(&delimiter))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poses() -> Vec<StampedRbt> {
+        (0..4)
+            .map(|i| {
+                let i = i as f32;
+                StampedRbt::new(
+                    0.25 * i as f64,
+                    Rbt::from_t_r(
+                        Vec3::new(i, -2.0 * i, 0.5 + i),
+                        Quat::from_euler(EulerRot::ZYX, 0.3 * i, -0.2, 0.1 * i),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn round_trip(format: &CsvFormat) -> Vec<StampedRbt> {
+        let mut buf = Vec::new();
+        format.write(&mut buf, &poses()).unwrap();
+        format.read(buf.as_slice()).unwrap()
+    }
+
+    fn assert_close(a: &[StampedRbt], b: &[StampedRbt]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert_eq!(a.time, b.time);
+            assert!(a.rbt.translation.abs_diff_eq(b.rbt.translation, 1e-6));
+            assert!(a.rbt.rotation.dot(b.rbt.rotation).abs() > 1.0 - 1e-6);
+        }
+    }
+
+    #[test]
+    fn round_trips_every_layout() {
+        let layouts = [
+            Layout::XyzQuat,
+            Layout::XyzEuler {
+                order: EulerRot::ZYX,
+                degrees: false,
+            },
+            Layout::XyzEuler {
+                order: EulerRot::XYZ,
+                degrees: true,
+            },
+            Layout::Mat3x4,
+        ];
+        for layout in layouts {
+            let format = CsvFormat {
+                layout,
+                ..Default::default()
+            };
+            assert_close(&round_trip(&format), &poses());
+        }
+    }
+
+    #[test]
+    fn reads_whitespace_delimiters() {
+        let format = CsvFormat {
+            delimiter: ' ',
+            header: false,
+            ..Default::default()
+        };
+        assert_close(&round_trip(&format), &poses());
+
+        let text = "# comment\ntime x y z qx qy qz qw\n\n 1.5\t1  2 \t3 0 0 0 1 \n";
+        let read = format.read(text.as_bytes());
+        assert!(read.is_err(), "header must be skipped only when enabled");
+        let format = CsvFormat {
+            header: true,
+            ..format
+        };
+        let read = format.read(text.as_bytes()).unwrap();
+        assert_eq!(
+            read,
+            [StampedRbt::new(1.5, Rbt::from_t(Vec3::new(1.0, 2.0, 3.0)))]
+        );
+    }
+
+    #[test]
+    fn rejects_zero_quaternion() {
+        let text = "time,x,y,z,qx,qy,qz,qw\n0,1,2,3,0,0,0,0\n";
+        match CsvFormat::default().read(text.as_bytes()) {
+            Err(CsvError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("{other:?}"),
+        }
+    }
+}
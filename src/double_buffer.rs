@@ -0,0 +1,91 @@
+//! Pose snapshots shared between threads.
+
+use crate::Rbt;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Poses of many objects at one time.
+pub struct PoseSnapshot {
+    /// The time in seconds.
+    pub time: f64,
+    /// The poses.
+    pub poses: Vec<Rbt>,
+}
+
+impl PoseSnapshot {
+    /// Creates a snapshot.
+    pub fn new(time: f64, poses: Vec<Rbt>) -> Self {
+        PoseSnapshot { time, poses }
+    }
+}
+
+#[derive(Debug, Default)]
+/// The latest two published `PoseSnapshot`s, for a simulation thread writing and render threads reading.
+///
+/// Readers always see a complete snapshot, never a partially written one. Share it with `Arc`.
+pub struct TransformDoubleBuffer {
+    /// The previous and current snapshots.
+    snapshots: Mutex<(Arc<PoseSnapshot>, Arc<PoseSnapshot>)>,
+}
+
+impl TransformDoubleBuffer {
+    /// Creates a buffer with both snapshots `initial`.
+    pub fn new(initial: PoseSnapshot) -> Self {
+        let initial = Arc::new(initial);
+        TransformDoubleBuffer {
+            snapshots: Mutex::new((initial.clone(), initial)),
+        }
+    }
+
+    /// Makes `snapshot` current and the current one previous.
+    ///
+    /// Returns the snapshot dropped out of the buffer if no reader holds it anymore, so its allocation can be
+    /// reused for the next one.
+    pub fn publish(&self, snapshot: PoseSnapshot) -> Option<PoseSnapshot> {
+        let retired = {
+            let mut snapshots = self.lock();
+            let current = std::mem::replace(&mut snapshots.1, Arc::new(snapshot));
+            std::mem::replace(&mut snapshots.0, current)
+        };
+        Arc::try_unwrap(retired).ok()
+    }
+
+    /// Returns the current snapshot.
+    pub fn current(&self) -> Arc<PoseSnapshot> {
+        self.lock().1.clone()
+    }
+
+    /// Returns the previous and current snapshots, published one after the other.
+    pub fn snapshots(&self) -> (Arc<PoseSnapshot>, Arc<PoseSnapshot>) {
+        self.lock().clone()
+    }
+
+    /// Writes the poses interpolated between the previous and current snapshots at `time` to `out`.
+    ///
+    /// `time` is clamped to the snapshot times. Objects only in the current snapshot get their current pose.
+    pub fn interpolate(&self, time: f64, out: &mut Vec<Rbt>) {
+        let (previous, current) = self.snapshots();
+        let span = current.time - previous.time;
+        let t = if span > 0.0 {
+            ((time - previous.time) / span).clamp(0.0, 1.0) as f32
+        } else {
+            1.0
+        };
+        out.clear();
+        out.extend(
+            current
+                .poses
+                .iter()
+                .enumerate()
+                .map(|(i, c)| match previous.poses.get(i) {
+                    Some(p) => p.lerp(c, t),
+                    None => c.clone(),
+                }),
+        );
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, (Arc<PoseSnapshot>, Arc<PoseSnapshot>)> {
+        // The guarded swaps can't panic halfway, so a poisoned lock still holds coherent snapshots.
+        self.snapshots.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
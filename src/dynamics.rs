@@ -0,0 +1,89 @@
+//! Rigid body state integration.
+
+use crate::lie::so3_exp;
+use crate::{Quat, Rbt, Vec3};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A pose with linear and angular velocity, both in the world frame.
+pub struct RigidBodyState {
+    /// The body-to-world pose.
+    pub pose: Rbt,
+    /// The velocity of the body origin.
+    pub linear_vel: Vec3,
+    /// The angular velocity, in radians per second.
+    pub angular_vel: Vec3,
+}
+
+/// The time derivative of a state.
+struct Derivative {
+    translation: Vec3,
+    rotation: Quat,
+    linear_vel: Vec3,
+    angular_vel: Vec3,
+}
+
+impl RigidBodyState {
+    /// Creates a state at rest.
+    pub fn new(pose: Rbt) -> Self {
+        RigidBodyState {
+            pose,
+            ..Default::default()
+        }
+    }
+
+    /// Steps by `dt` with semi-implicit Euler: velocities are updated first, then the pose with the new velocities.
+    ///
+    /// Accelerations are in the world frame. The rotation is integrated exactly for the new angular velocity.
+    pub fn step_semi_implicit(&mut self, linear_accel: Vec3, angular_accel: Vec3, dt: f32) {
+        self.linear_vel += linear_accel * dt;
+        self.angular_vel += angular_accel * dt;
+        self.pose.translation += self.linear_vel * dt;
+        self.pose.rotation = (so3_exp(self.angular_vel * dt) * self.pose.rotation).normalize();
+    }
+
+    /// Steps by `dt` with classic Runge-Kutta.
+    ///
+    /// `accel` returns the linear and angular accelerations in the world frame at a state.
+    pub fn step_rk4(&mut self, dt: f32, accel: impl Fn(&RigidBodyState) -> (Vec3, Vec3)) {
+        let derivative = |s: &RigidBodyState| {
+            let (linear, angular) = accel(s);
+            Derivative {
+                translation: s.linear_vel,
+                rotation: Quat::from_xyzw(s.angular_vel.x, s.angular_vel.y, s.angular_vel.z, 0.0)
+                    * s.pose.rotation
+                    * 0.5,
+                linear_vel: linear,
+                angular_vel: angular,
+            }
+        };
+        let offset = |d: &Derivative, h: f32| RigidBodyState {
+            pose: Rbt::from_t_r(
+                self.pose.translation + d.translation * h,
+                (self.pose.rotation + d.rotation * h).normalize(),
+            ),
+            linear_vel: self.linear_vel + d.linear_vel * h,
+            angular_vel: self.angular_vel + d.angular_vel * h,
+        };
+        let k1 = derivative(self);
+        let k2 = derivative(&offset(&k1, dt * 0.5));
+        let k3 = derivative(&offset(&k2, dt * 0.5));
+        let k4 = derivative(&offset(&k3, dt));
+        let sum = Derivative {
+            translation: k1.translation + (k2.translation + k3.translation) * 2.0 + k4.translation,
+            rotation: k1.rotation + (k2.rotation + k3.rotation) * 2.0 + k4.rotation,
+            linear_vel: k1.linear_vel + (k2.linear_vel + k3.linear_vel) * 2.0 + k4.linear_vel,
+            angular_vel: k1.angular_vel + (k2.angular_vel + k3.angular_vel) * 2.0 + k4.angular_vel,
+        };
+        *self = offset(&sum, dt / 6.0);
+    }
+
+    /// Returns the world frame angular acceleration of torque-free motion, from Euler's equations.
+    ///
+    /// `inertia` holds the principal moments of inertia along the body axes.
+    pub fn torque_free_angular_accel(&self, inertia: Vec3) -> Vec3 {
+        let to_body = self.pose.rotation.conjugate();
+        let w = to_body * self.angular_vel;
+        let l = inertia * w;
+        self.pose.rotation * (-w.cross(l) / inertia)
+    }
+}
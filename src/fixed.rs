@@ -0,0 +1,168 @@
+//! Fixed-point rigid body transform for deterministic simulation.
+
+use crate::{Quat, Rbt, Vec3};
+use core::ops::Mul;
+
+/// Fractional bits of translation components.
+const T_FRAC: u32 = 32;
+/// Fractional bits of rotation components.
+const R_FRAC: u32 = 30;
+/// 1.0 in rotation fixed point.
+const R_ONE: i64 = 1 << R_FRAC;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A rigid body transform using only integer arithmetic.
+///
+/// Results are bit-identical on every platform.
+pub struct FixedRbt {
+    /// The translation in 32.32 fixed point.
+    pub translation: [i64; 3],
+    /// The rotation quaternion as `[x, y, z, w]` in 2.30 fixed point.
+    pub rotation: [i32; 4],
+}
+
+impl Default for FixedRbt {
+    fn default() -> Self {
+        FixedRbt {
+            translation: [0; 3],
+            rotation: [0, 0, 0, R_ONE as i32],
+        }
+    }
+}
+
+impl FixedRbt {
+    /// Returns the identity transform.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Converts from a float transform, rounding to the nearest representable value.
+    ///
+    /// The rotation is normalized in fixed point.
+    pub fn from_rbt(rbt: &Rbt) -> Self {
+        let t = rbt.translation.to_array().map(|x| to_fixed(x, T_FRAC));
+        let r = rbt
+            .rotation
+            .normalize()
+            .to_array()
+            .map(|x| to_fixed(x, R_FRAC));
+        FixedRbt {
+            translation: t,
+            rotation: normalize([r[0] as i32, r[1] as i32, r[2] as i32, r[3] as i32]),
+        }
+    }
+
+    /// Converts to a float transform.
+    pub fn to_rbt(&self) -> Rbt {
+        Rbt {
+            translation: Vec3::from_array(self.translation.map(|x| from_fixed(x, T_FRAC))),
+            rotation: Quat::from_array(self.rotation.map(|x| from_fixed(x as i64, R_FRAC))),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let [x, y, z, w] = self.rotation;
+        let inv_r = [-x, -y, -z, w];
+        let t = rotate(inv_r, self.translation);
+        FixedRbt {
+            translation: [-t[0], -t[1], -t[2]],
+            rotation: inv_r,
+        }
+    }
+
+    /// Transforms a point in 32.32 fixed point.
+    pub fn transform_point(&self, point: [i64; 3]) -> [i64; 3] {
+        let p = rotate(self.rotation, point);
+        [
+            p[0].wrapping_add(self.translation[0]),
+            p[1].wrapping_add(self.translation[1]),
+            p[2].wrapping_add(self.translation[2]),
+        ]
+    }
+}
+
+fn to_fixed(x: f32, frac: u32) -> i64 {
+    crate::math::round_f64(x as f64 * (1u64 << frac) as f64) as i64
+}
+
+fn from_fixed(x: i64, frac: u32) -> f32 {
+    (x as f64 / (1u64 << frac) as f64) as f32
+}
+
+/// Shifts right by `R_FRAC`, rounding to nearest.
+fn round_shift(x: i128) -> i64 {
+    ((x + (1 << (R_FRAC - 1))) >> R_FRAC) as i64
+}
+
+fn mul_quat(a: [i32; 4], b: [i32; 4]) -> [i32; 4] {
+    let [ax, ay, az, aw] = a.map(|x| x as i128);
+    let [bx, by, bz, bw] = b.map(|x| x as i128);
+    normalize([
+        round_shift(aw * bx + ax * bw + ay * bz - az * by) as i32,
+        round_shift(aw * by - ax * bz + ay * bw + az * bx) as i32,
+        round_shift(aw * bz + ax * by - ay * bx + az * bw) as i32,
+        round_shift(aw * bw - ax * bx - ay * by - az * bz) as i32,
+    ])
+}
+
+/// Normalizes a quaternion, returning the identity if it's zero.
+fn normalize(q: [i32; 4]) -> [i32; 4] {
+    let n2: u64 = q.iter().map(|&x| (x as i64 * x as i64) as u64).sum();
+    let len = n2.isqrt() as i64;
+    if len == 0 {
+        return FixedRbt::default().rotation;
+    }
+    q.map(|x| (((x as i64) << R_FRAC) / len) as i32)
+}
+
+fn rotate(q: [i32; 4], v: [i64; 3]) -> [i64; 3] {
+    let [x, y, z, w] = q.map(|x| x as i128);
+    let p = |a: i128, b: i128| round_shift(a * b) as i128;
+    let (xx, yy, zz) = (p(x, x), p(y, y), p(z, z));
+    let (xy, xz, yz) = (p(x, y), p(x, z), p(y, z));
+    let (wx, wy, wz) = (p(w, x), p(w, y), p(w, z));
+    let one = R_ONE as i128;
+    let m = [
+        [one - 2 * (yy + zz), 2 * (xy - wz), 2 * (xz + wy)],
+        [2 * (xy + wz), one - 2 * (xx + zz), 2 * (yz - wx)],
+        [2 * (xz - wy), 2 * (yz + wx), one - 2 * (xx + yy)],
+    ];
+    let v = v.map(|x| x as i128);
+    m.map(|row| round_shift(row[0] * v[0] + row[1] * v[1] + row[2] * v[2]))
+}
+
+impl Mul<&FixedRbt> for &FixedRbt {
+    type Output = FixedRbt;
+
+    fn mul(self, rhs: &FixedRbt) -> FixedRbt {
+        FixedRbt {
+            translation: self.transform_point(rhs.translation),
+            rotation: mul_quat(self.rotation, rhs.rotation),
+        }
+    }
+}
+
+impl Mul<FixedRbt> for &FixedRbt {
+    type Output = FixedRbt;
+
+    fn mul(self, rhs: FixedRbt) -> FixedRbt {
+        self * &rhs
+    }
+}
+
+impl Mul<&FixedRbt> for FixedRbt {
+    type Output = FixedRbt;
+
+    fn mul(self, rhs: &FixedRbt) -> FixedRbt {
+        &self * rhs
+    }
+}
+
+impl Mul<FixedRbt> for FixedRbt {
+    type Output = FixedRbt;
+
+    fn mul(self, rhs: FixedRbt) -> FixedRbt {
+        &self * &rhs
+    }
+}
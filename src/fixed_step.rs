@@ -0,0 +1,104 @@
+//! Render interpolation for fixed timestep simulations.
+
+use crate::Rbt;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// The poses of many objects at the previous and current simulation steps, for rendering in between.
+pub struct FixedStepInterpolator {
+    previous: Vec<Rbt>,
+    current: Vec<Rbt>,
+}
+
+impl FixedStepInterpolator {
+    /// Creates an interpolator at rest at `poses`.
+    pub fn new(poses: Vec<Rbt>) -> Self {
+        FixedStepInterpolator {
+            previous: poses.clone(),
+            current: poses,
+        }
+    }
+
+    /// Returns the number of objects.
+    pub fn len(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Returns whether there are no objects.
+    pub fn is_empty(&self) -> bool {
+        self.current.is_empty()
+    }
+
+    /// Adds an object at rest at `pose`. Returns its index.
+    pub fn push(&mut self, pose: Rbt) -> usize {
+        self.previous.push(pose.clone());
+        self.current.push(pose);
+        self.current.len() - 1
+    }
+
+    /// Returns the poses at the previous step.
+    pub fn previous(&self) -> &[Rbt] {
+        &self.previous
+    }
+
+    /// Returns the poses at the current step.
+    pub fn current(&self) -> &[Rbt] {
+        &self.current
+    }
+
+    /// Starts a new step: the current poses become the previous ones, and stay current until overwritten.
+    pub fn begin_step(&mut self) {
+        self.previous.clone_from_slice(&self.current);
+    }
+
+    /// Sets the current pose of object `index`, reached from the previous one at alpha 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, pose: Rbt) {
+        self.current[index] = pose;
+    }
+
+    /// Starts a new step with the current poses `poses`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poses` length is not `len()`.
+    pub fn step(&mut self, poses: &[Rbt]) {
+        assert_eq!(poses.len(), self.current.len(), "wrong number of poses");
+        std::mem::swap(&mut self.previous, &mut self.current);
+        self.current.clone_from_slice(poses);
+    }
+
+    /// Moves object `index` to `pose` at both steps, so it jumps rather than sweeps there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn teleport(&mut self, index: usize, pose: Rbt) {
+        self.previous[index] = pose.clone();
+        self.current[index] = pose;
+    }
+
+    /// Returns the pose of object `index` at `alpha` between the previous step, at 0, and the current one, at 1.
+    ///
+    /// Rotations take the shortest path, whatever the signs of the quaternions.
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn interpolate(&self, index: usize, alpha: f32) -> Rbt {
+        self.previous[index].lerp(&self.current[index], alpha)
+    }
+
+    /// Writes the poses of all objects at `alpha` to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` length is not `len()`.
+    pub fn interpolate_all(&self, alpha: f32, out: &mut [Rbt]) {
+        assert_eq!(out.len(), self.current.len(), "wrong output length");
+        for ((out, previous), current) in out.iter_mut().zip(&self.previous).zip(&self.current) {
+            *out = previous.lerp(current, alpha);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! FlatBuffers pose messages.
+//!
+//! The schema is in `proto/rbt3.fbs`, mirroring `proto/rbt3.proto`, so other services can generate compatible types.
+//! The message types are generated by `planus`; write them with a `planus::Builder`,
+//! and read them with `planus::ReadAsRoot`.
+
+use crate::uncertainty::{CovarianceLenError, UncertainRbt};
+use crate::{Quat, Rbt, StampedRbt, Vec3};
+
+#[allow(missing_docs, unsafe_code, unused_qualifications, unused_results)]
+#[rustfmt::skip]
+mod generated;
+
+pub use generated::rbt3::*;
+
+impl From<&Rbt> for Pose {
+    fn from(rbt: &Rbt) -> Self {
+        let (t, r) = (rbt.translation, rbt.rotation);
+        Pose {
+            position: Vector3 {
+                x: t.x as f64,
+                y: t.y as f64,
+                z: t.z as f64,
+            },
+            orientation: Quaternion {
+                x: r.x as f64,
+                y: r.y as f64,
+                z: r.z as f64,
+                w: r.w as f64,
+            },
+        }
+    }
+}
+
+impl From<&Pose> for Rbt {
+    fn from(pose: &Pose) -> Self {
+        let (t, r) = (pose.position, pose.orientation);
+        Rbt::from_t_r(
+            Vec3::new(t.x as f32, t.y as f32, t.z as f32),
+            Quat::from_xyzw(r.x as f32, r.y as f32, r.z as f32, r.w as f32).normalize(),
+        )
+    }
+}
+
+impl From<PoseRef<'_>> for Rbt {
+    fn from(pose: PoseRef<'_>) -> Self {
+        (&Pose::from(pose)).into()
+    }
+}
+
+impl From<&UncertainRbt> for PoseWithCovariance {
+    fn from(uncertain: &UncertainRbt) -> Self {
+        PoseWithCovariance {
+            pose: Some((&uncertain.mean).into()),
+            covariance: Some(
+                uncertain
+                    .covariance
+                    .iter()
+                    .flatten()
+                    .map(|&x| x as f64)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl TryFrom<&PoseWithCovariance> for UncertainRbt {
+    type Error = CovarianceLenError;
+
+    /// A missing pose is taken as identity.
+    fn try_from(message: &PoseWithCovariance) -> Result<Self, Self::Error> {
+        let values = message.covariance.as_deref().unwrap_or_default();
+        let covariance: &[f64; 36] = values
+            .try_into()
+            .map_err(|_| CovarianceLenError { len: values.len() })?;
+        Ok(UncertainRbt::new(
+            message.pose.as_ref().map(Rbt::from).unwrap_or_default(),
+            core::array::from_fn(|i| core::array::from_fn(|j| covariance[6 * i + j] as f32)),
+        ))
+    }
+}
+
+impl From<&StampedRbt> for StampedPose {
+    /// Frame names are left unset.
+    fn from(stamped: &StampedRbt) -> Self {
+        StampedPose {
+            time: stamped.time,
+            frame: None,
+            child_frame: None,
+            pose: Some((&stamped.rbt).into()),
+        }
+    }
+}
+
+impl From<&StampedPose> for StampedRbt {
+    /// A missing pose is taken as identity.
+    fn from(stamped: &StampedPose) -> Self {
+        StampedRbt::new(
+            stamped.time,
+            stamped.pose.as_ref().map(Rbt::from).unwrap_or_default(),
+        )
+    }
+}
+
+impl TryFrom<StampedPoseRef<'_>> for StampedRbt {
+    type Error = planus::Error;
+
+    /// A missing pose is taken as identity.
+    fn try_from(stamped: StampedPoseRef<'_>) -> Result<Self, Self::Error> {
+        Ok(StampedRbt::new(
+            stamped.time()?,
+            stamped.pose()?.map(Rbt::from).unwrap_or_default(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use planus::{Builder, ReadAsRoot};
+
+    fn rbt() -> Rbt {
+        Rbt::from_t_r(
+            Vec3::new(1.0, -2.0, 0.5),
+            Quat::from_axis_angle(Vec3::new(0.3, -0.5, 0.8).normalize(), 1.2),
+        )
+    }
+
+    #[test]
+    fn rbt_round_trips() {
+        let decoded = Rbt::from(&Pose::from(&rbt()));
+        assert_eq!(decoded.translation, rbt().translation);
+        assert!(decoded.rotation.abs_diff_eq(rbt().rotation, 1e-7));
+    }
+
+    #[test]
+    fn stamped_round_trips() {
+        let stamped = StampedRbt::new(12.25, rbt());
+        let mut builder = Builder::new();
+        let bytes = builder.finish(StampedPose::from(&stamped), None);
+        let decoded = StampedRbt::try_from(StampedPoseRef::read_as_root(bytes).unwrap()).unwrap();
+        assert_eq!(decoded.time, stamped.time);
+        assert_eq!(decoded.rbt.translation, stamped.rbt.translation);
+        assert!(decoded.rbt.rotation.abs_diff_eq(stamped.rbt.rotation, 1e-7));
+    }
+
+    #[test]
+    fn uncertain_round_trips() {
+        let covariance = core::array::from_fn(|i| core::array::from_fn(|j| (i * 6 + j) as f32));
+        let uncertain = UncertainRbt::new(rbt(), covariance);
+        let mut builder = Builder::new();
+        let bytes = builder.finish(PoseWithCovariance::from(&uncertain), None);
+        let message =
+            PoseWithCovariance::try_from(PoseWithCovarianceRef::read_as_root(bytes).unwrap())
+                .unwrap();
+        let decoded = UncertainRbt::try_from(&message).unwrap();
+        assert_eq!(decoded.covariance, covariance);
+        assert_eq!(decoded.mean.translation, uncertain.mean.translation);
+    }
+
+    #[test]
+    fn rejects_wrong_covariance_length() {
+        let mut message = PoseWithCovariance::from(&UncertainRbt::default());
+        message.covariance = None;
+        assert_eq!(
+            UncertainRbt::try_from(&message),
+            Err(CovarianceLenError { len: 0 })
+        );
+    }
+}
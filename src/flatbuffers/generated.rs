@@ -0,0 +1,1374 @@
+pub use root::*;
+
+const _: () = ::planus::check_version_compatibility("planus-1.3.0");
+
+/// The root namespace
+///
+/// Generated from these locations:
+/// * File `proto/rbt3.fbs`
+#[no_implicit_prelude]
+#[allow(clippy::needless_lifetimes)]
+mod root {
+    /// The namespace `rbt3`
+    ///
+    /// Generated from these locations:
+    /// * File `proto/rbt3.fbs`
+    pub mod rbt3 {
+        /// The struct `Vector3` in the namespace `rbt3`
+        ///
+        /// Generated from these locations:
+        /// * Struct `Vector3` in the file `proto/rbt3.fbs:12`
+        #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+        pub struct Vector3 {
+            /// The field `x` in the struct `Vector3`
+            pub x: f64,
+
+            /// The field `y` in the struct `Vector3`
+            pub y: f64,
+
+            /// The field `z` in the struct `Vector3`
+            pub z: f64,
+        }
+
+        /// # Safety
+        /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+        unsafe impl ::planus::Primitive for Vector3 {
+            const ALIGNMENT: usize = 8;
+            const SIZE: usize = 24;
+        }
+
+        #[allow(clippy::identity_op)]
+        impl ::planus::WriteAsPrimitive<Vector3> for Vector3 {
+            #[inline]
+            fn write<const N: usize>(&self, cursor: ::planus::Cursor<'_, N>, buffer_position: u32) {
+                let (cur, cursor) = cursor.split::<8, 16>();
+                self.x.write(cur, buffer_position - 0);
+                let (cur, cursor) = cursor.split::<8, 8>();
+                self.y.write(cur, buffer_position - 8);
+                let (cur, cursor) = cursor.split::<8, 0>();
+                self.z.write(cur, buffer_position - 16);
+                cursor.finish([]);
+            }
+        }
+
+        impl ::planus::WriteAsOffset<Vector3> for Vector3 {
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Vector3> {
+                unsafe {
+                    builder.write_with(24, 7, |buffer_position, bytes| {
+                        let bytes = bytes.as_mut_ptr();
+
+                        ::planus::WriteAsPrimitive::write(
+                            self,
+                            ::planus::Cursor::new(
+                                &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 24]),
+                            ),
+                            buffer_position,
+                        );
+                    });
+                }
+                builder.current_offset()
+            }
+        }
+
+        impl ::planus::WriteAs<Vector3> for Vector3 {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                *self
+            }
+        }
+
+        impl ::planus::WriteAsOptional<Vector3> for Vector3 {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> ::core::option::Option<Self> {
+                ::core::option::Option::Some(*self)
+            }
+        }
+
+        /// Reference to a deserialized [Vector3].
+        #[derive(Copy, Clone)]
+        pub struct Vector3Ref<'a>(::planus::ArrayWithStartOffset<'a, 24>);
+
+        impl<'a> Vector3Ref<'a> {
+            /// Getter for the [`x` field](Vector3#structfield.x).
+            pub fn x(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(0).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+
+            /// Getter for the [`y` field](Vector3#structfield.y).
+            pub fn y(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(8).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+
+            /// Getter for the [`z` field](Vector3#structfield.z).
+            pub fn z(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(16).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+        }
+
+        impl<'a> ::core::fmt::Debug for Vector3Ref<'a> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut f = f.debug_struct("Vector3Ref");
+                f.field("x", &self.x());
+                f.field("y", &self.y());
+                f.field("z", &self.z());
+                f.finish()
+            }
+        }
+
+        impl<'a> ::core::convert::From<::planus::ArrayWithStartOffset<'a, 24>> for Vector3Ref<'a> {
+            fn from(array: ::planus::ArrayWithStartOffset<'a, 24>) -> Self {
+                Self(array)
+            }
+        }
+
+        impl<'a> ::core::convert::From<Vector3Ref<'a>> for Vector3 {
+            #[allow(unreachable_code)]
+            fn from(value: Vector3Ref<'a>) -> Self {
+                Self {
+                    x: value.x(),
+                    y: value.y(),
+                    z: value.z(),
+                }
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialEq<Vector3Ref<'a>> for Vector3Ref<'b> {
+            fn eq(&self, other: &Vector3Ref<'_>) -> bool {
+                self.x() == other.x() && self.y() == other.y() && self.z() == other.z()
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialOrd<Vector3Ref<'a>> for Vector3Ref<'b> {
+            fn partial_cmp(
+                &self,
+                other: &Vector3Ref<'_>,
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                match self.x().partial_cmp(&other.x()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                match self.y().partial_cmp(&other.y()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                self.z().partial_cmp(&other.z())
+            }
+        }
+
+        impl<'a> ::planus::TableRead<'a> for Vector3Ref<'a> {
+            #[inline]
+            fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                let buffer = buffer.advance_as_array::<24>(offset)?;
+                ::core::result::Result::Ok(Self(buffer))
+            }
+        }
+
+        impl<'a> ::planus::VectorRead<'a> for Vector3Ref<'a> {
+            const STRIDE: usize = 24;
+
+            #[inline]
+            unsafe fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> Self {
+                Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+            }
+        }
+
+        /// # Safety
+        /// The planus compiler generates implementations that initialize
+        /// the bytes in `write_values`.
+        unsafe impl ::planus::VectorWrite<Vector3> for Vector3 {
+            const STRIDE: usize = 24;
+
+            type Value = Vector3;
+
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                *self
+            }
+
+            #[inline]
+            unsafe fn write_values(
+                values: &[Vector3],
+                bytes: *mut ::core::mem::MaybeUninit<u8>,
+                buffer_position: u32,
+            ) {
+                let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 24];
+                for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                    ::planus::WriteAsPrimitive::write(
+                        v,
+                        ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                        buffer_position - (24 * i) as u32,
+                    );
+                }
+            }
+        }
+
+        /// The struct `Quaternion` in the namespace `rbt3`
+        ///
+        /// Generated from these locations:
+        /// * Struct `Quaternion` in the file `proto/rbt3.fbs:18`
+        #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+        pub struct Quaternion {
+            /// The field `x` in the struct `Quaternion`
+            pub x: f64,
+
+            /// The field `y` in the struct `Quaternion`
+            pub y: f64,
+
+            /// The field `z` in the struct `Quaternion`
+            pub z: f64,
+
+            /// The field `w` in the struct `Quaternion`
+            pub w: f64,
+        }
+
+        /// # Safety
+        /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+        unsafe impl ::planus::Primitive for Quaternion {
+            const ALIGNMENT: usize = 8;
+            const SIZE: usize = 32;
+        }
+
+        #[allow(clippy::identity_op)]
+        impl ::planus::WriteAsPrimitive<Quaternion> for Quaternion {
+            #[inline]
+            fn write<const N: usize>(&self, cursor: ::planus::Cursor<'_, N>, buffer_position: u32) {
+                let (cur, cursor) = cursor.split::<8, 24>();
+                self.x.write(cur, buffer_position - 0);
+                let (cur, cursor) = cursor.split::<8, 16>();
+                self.y.write(cur, buffer_position - 8);
+                let (cur, cursor) = cursor.split::<8, 8>();
+                self.z.write(cur, buffer_position - 16);
+                let (cur, cursor) = cursor.split::<8, 0>();
+                self.w.write(cur, buffer_position - 24);
+                cursor.finish([]);
+            }
+        }
+
+        impl ::planus::WriteAsOffset<Quaternion> for Quaternion {
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Quaternion> {
+                unsafe {
+                    builder.write_with(32, 7, |buffer_position, bytes| {
+                        let bytes = bytes.as_mut_ptr();
+
+                        ::planus::WriteAsPrimitive::write(
+                            self,
+                            ::planus::Cursor::new(
+                                &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 32]),
+                            ),
+                            buffer_position,
+                        );
+                    });
+                }
+                builder.current_offset()
+            }
+        }
+
+        impl ::planus::WriteAs<Quaternion> for Quaternion {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                *self
+            }
+        }
+
+        impl ::planus::WriteAsOptional<Quaternion> for Quaternion {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> ::core::option::Option<Self> {
+                ::core::option::Option::Some(*self)
+            }
+        }
+
+        /// Reference to a deserialized [Quaternion].
+        #[derive(Copy, Clone)]
+        pub struct QuaternionRef<'a>(::planus::ArrayWithStartOffset<'a, 32>);
+
+        impl<'a> QuaternionRef<'a> {
+            /// Getter for the [`x` field](Quaternion#structfield.x).
+            pub fn x(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(0).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+
+            /// Getter for the [`y` field](Quaternion#structfield.y).
+            pub fn y(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(8).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+
+            /// Getter for the [`z` field](Quaternion#structfield.z).
+            pub fn z(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(16).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+
+            /// Getter for the [`w` field](Quaternion#structfield.w).
+            pub fn w(&self) -> f64 {
+                let buffer = self.0.advance_as_array::<8>(24).unwrap();
+
+                f64::from_le_bytes(*buffer.as_array())
+            }
+        }
+
+        impl<'a> ::core::fmt::Debug for QuaternionRef<'a> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut f = f.debug_struct("QuaternionRef");
+                f.field("x", &self.x());
+                f.field("y", &self.y());
+                f.field("z", &self.z());
+                f.field("w", &self.w());
+                f.finish()
+            }
+        }
+
+        impl<'a> ::core::convert::From<::planus::ArrayWithStartOffset<'a, 32>> for QuaternionRef<'a> {
+            fn from(array: ::planus::ArrayWithStartOffset<'a, 32>) -> Self {
+                Self(array)
+            }
+        }
+
+        impl<'a> ::core::convert::From<QuaternionRef<'a>> for Quaternion {
+            #[allow(unreachable_code)]
+            fn from(value: QuaternionRef<'a>) -> Self {
+                Self {
+                    x: value.x(),
+                    y: value.y(),
+                    z: value.z(),
+                    w: value.w(),
+                }
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialEq<QuaternionRef<'a>> for QuaternionRef<'b> {
+            fn eq(&self, other: &QuaternionRef<'_>) -> bool {
+                self.x() == other.x()
+                    && self.y() == other.y()
+                    && self.z() == other.z()
+                    && self.w() == other.w()
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialOrd<QuaternionRef<'a>> for QuaternionRef<'b> {
+            fn partial_cmp(
+                &self,
+                other: &QuaternionRef<'_>,
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                match self.x().partial_cmp(&other.x()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                match self.y().partial_cmp(&other.y()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                match self.z().partial_cmp(&other.z()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                self.w().partial_cmp(&other.w())
+            }
+        }
+
+        impl<'a> ::planus::TableRead<'a> for QuaternionRef<'a> {
+            #[inline]
+            fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                let buffer = buffer.advance_as_array::<32>(offset)?;
+                ::core::result::Result::Ok(Self(buffer))
+            }
+        }
+
+        impl<'a> ::planus::VectorRead<'a> for QuaternionRef<'a> {
+            const STRIDE: usize = 32;
+
+            #[inline]
+            unsafe fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> Self {
+                Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+            }
+        }
+
+        /// # Safety
+        /// The planus compiler generates implementations that initialize
+        /// the bytes in `write_values`.
+        unsafe impl ::planus::VectorWrite<Quaternion> for Quaternion {
+            const STRIDE: usize = 32;
+
+            type Value = Quaternion;
+
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                *self
+            }
+
+            #[inline]
+            unsafe fn write_values(
+                values: &[Quaternion],
+                bytes: *mut ::core::mem::MaybeUninit<u8>,
+                buffer_position: u32,
+            ) {
+                let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 32];
+                for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                    ::planus::WriteAsPrimitive::write(
+                        v,
+                        ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                        buffer_position - (32 * i) as u32,
+                    );
+                }
+            }
+        }
+
+        /// The struct `Pose` in the namespace `rbt3`
+        ///
+        /// Generated from these locations:
+        /// * Struct `Pose` in the file `proto/rbt3.fbs:25`
+        #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+        pub struct Pose {
+            /// The field `position` in the struct `Pose`
+            pub position: self::Vector3,
+
+            /// The field `orientation` in the struct `Pose`
+            pub orientation: self::Quaternion,
+        }
+
+        /// # Safety
+        /// The Planus compiler correctly calculates `ALIGNMENT` and `SIZE`.
+        unsafe impl ::planus::Primitive for Pose {
+            const ALIGNMENT: usize = 8;
+            const SIZE: usize = 56;
+        }
+
+        #[allow(clippy::identity_op)]
+        impl ::planus::WriteAsPrimitive<Pose> for Pose {
+            #[inline]
+            fn write<const N: usize>(&self, cursor: ::planus::Cursor<'_, N>, buffer_position: u32) {
+                let (cur, cursor) = cursor.split::<24, 32>();
+                self.position.write(cur, buffer_position - 0);
+                let (cur, cursor) = cursor.split::<32, 0>();
+                self.orientation.write(cur, buffer_position - 24);
+                cursor.finish([]);
+            }
+        }
+
+        impl ::planus::WriteAsOffset<Pose> for Pose {
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<Pose> {
+                unsafe {
+                    builder.write_with(56, 7, |buffer_position, bytes| {
+                        let bytes = bytes.as_mut_ptr();
+
+                        ::planus::WriteAsPrimitive::write(
+                            self,
+                            ::planus::Cursor::new(
+                                &mut *(bytes as *mut [::core::mem::MaybeUninit<u8>; 56]),
+                            ),
+                            buffer_position,
+                        );
+                    });
+                }
+                builder.current_offset()
+            }
+        }
+
+        impl ::planus::WriteAs<Pose> for Pose {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self {
+                *self
+            }
+        }
+
+        impl ::planus::WriteAsOptional<Pose> for Pose {
+            type Prepared = Self;
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> ::core::option::Option<Self> {
+                ::core::option::Option::Some(*self)
+            }
+        }
+
+        /// Reference to a deserialized [Pose].
+        #[derive(Copy, Clone)]
+        pub struct PoseRef<'a>(::planus::ArrayWithStartOffset<'a, 56>);
+
+        impl<'a> PoseRef<'a> {
+            /// Getter for the [`position` field](Pose#structfield.position).
+            pub fn position(&self) -> self::Vector3Ref<'a> {
+                let buffer = self.0.advance_as_array::<24>(0).unwrap();
+
+                ::core::convert::From::from(buffer)
+            }
+
+            /// Getter for the [`orientation` field](Pose#structfield.orientation).
+            pub fn orientation(&self) -> self::QuaternionRef<'a> {
+                let buffer = self.0.advance_as_array::<32>(24).unwrap();
+
+                ::core::convert::From::from(buffer)
+            }
+        }
+
+        impl<'a> ::core::fmt::Debug for PoseRef<'a> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut f = f.debug_struct("PoseRef");
+                f.field("position", &self.position());
+                f.field("orientation", &self.orientation());
+                f.finish()
+            }
+        }
+
+        impl<'a> ::core::convert::From<::planus::ArrayWithStartOffset<'a, 56>> for PoseRef<'a> {
+            fn from(array: ::planus::ArrayWithStartOffset<'a, 56>) -> Self {
+                Self(array)
+            }
+        }
+
+        impl<'a> ::core::convert::From<PoseRef<'a>> for Pose {
+            #[allow(unreachable_code)]
+            fn from(value: PoseRef<'a>) -> Self {
+                Self {
+                    position: ::core::convert::From::from(value.position()),
+                    orientation: ::core::convert::From::from(value.orientation()),
+                }
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialEq<PoseRef<'a>> for PoseRef<'b> {
+            fn eq(&self, other: &PoseRef<'_>) -> bool {
+                self.position() == other.position() && self.orientation() == other.orientation()
+            }
+        }
+
+        impl<'a, 'b> ::core::cmp::PartialOrd<PoseRef<'a>> for PoseRef<'b> {
+            fn partial_cmp(
+                &self,
+                other: &PoseRef<'_>,
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                match self.position().partial_cmp(&other.position()) {
+                    ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                    o => return o,
+                }
+
+                self.orientation().partial_cmp(&other.orientation())
+            }
+        }
+
+        impl<'a> ::planus::TableRead<'a> for PoseRef<'a> {
+            #[inline]
+            fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                let buffer = buffer.advance_as_array::<56>(offset)?;
+                ::core::result::Result::Ok(Self(buffer))
+            }
+        }
+
+        impl<'a> ::planus::VectorRead<'a> for PoseRef<'a> {
+            const STRIDE: usize = 56;
+
+            #[inline]
+            unsafe fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> Self {
+                Self(unsafe { buffer.unchecked_advance_as_array(offset) })
+            }
+        }
+
+        /// # Safety
+        /// The planus compiler generates implementations that initialize
+        /// the bytes in `write_values`.
+        unsafe impl ::planus::VectorWrite<Pose> for Pose {
+            const STRIDE: usize = 56;
+
+            type Value = Pose;
+
+            #[inline]
+            fn prepare(&self, _builder: &mut ::planus::Builder) -> Self::Value {
+                *self
+            }
+
+            #[inline]
+            unsafe fn write_values(
+                values: &[Pose],
+                bytes: *mut ::core::mem::MaybeUninit<u8>,
+                buffer_position: u32,
+            ) {
+                let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 56];
+                for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                    ::planus::WriteAsPrimitive::write(
+                        v,
+                        ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                        buffer_position - (56 * i) as u32,
+                    );
+                }
+            }
+        }
+
+        /// The table `PoseWithCovariance` in the namespace `rbt3`
+        ///
+        /// Generated from these locations:
+        /// * Table `PoseWithCovariance` in the file `proto/rbt3.fbs:32`
+        #[derive(Clone, Debug, PartialEq, PartialOrd)]
+        pub struct PoseWithCovariance {
+            /// The field `pose` in the table `PoseWithCovariance`
+            pub pose: ::core::option::Option<self::Pose>,
+            /// The field `covariance` in the table `PoseWithCovariance`
+            pub covariance: ::core::option::Option<::planus::alloc::vec::Vec<f64>>,
+        }
+
+        #[allow(clippy::derivable_impls)]
+        impl ::core::default::Default for PoseWithCovariance {
+            fn default() -> Self {
+                Self {
+                    pose: ::core::default::Default::default(),
+                    covariance: ::core::default::Default::default(),
+                }
+            }
+        }
+
+        impl PoseWithCovariance {
+            /// Creates a [PoseWithCovarianceBuilder] for serializing an instance of this table.
+            #[inline]
+            pub fn builder() -> PoseWithCovarianceBuilder<()> {
+                PoseWithCovarianceBuilder(())
+            }
+
+            #[allow(clippy::too_many_arguments)]
+            pub fn create(
+                builder: &mut ::planus::Builder,
+                field_pose: impl ::planus::WriteAsOptional<self::Pose>,
+                field_covariance: impl ::planus::WriteAsOptional<::planus::Offset<[f64]>>,
+            ) -> ::planus::Offset<Self> {
+                let prepared_pose = field_pose.prepare(builder);
+                let prepared_covariance = field_covariance.prepare(builder);
+
+                let mut table_writer: ::planus::table_writer::TableWriter<8> =
+                    ::core::default::Default::default();
+                if prepared_pose.is_some() {
+                    table_writer.write_entry::<self::Pose>(0);
+                }
+                if prepared_covariance.is_some() {
+                    table_writer.write_entry::<::planus::Offset<[f64]>>(1);
+                }
+
+                unsafe {
+                    table_writer.finish(builder, |object_writer| {
+                        if let ::core::option::Option::Some(prepared_pose) = prepared_pose {
+                            object_writer.write::<_, _, 56>(&prepared_pose);
+                        }
+                        if let ::core::option::Option::Some(prepared_covariance) =
+                            prepared_covariance
+                        {
+                            object_writer.write::<_, _, 4>(&prepared_covariance);
+                        }
+                    });
+                }
+                builder.current_offset()
+            }
+        }
+
+        impl ::planus::WriteAs<::planus::Offset<PoseWithCovariance>> for PoseWithCovariance {
+            type Prepared = ::planus::Offset<Self>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::planus::Offset<PoseWithCovariance> {
+                ::planus::WriteAsOffset::prepare(self, builder)
+            }
+        }
+
+        impl ::planus::WriteAsOptional<::planus::Offset<PoseWithCovariance>> for PoseWithCovariance {
+            type Prepared = ::planus::Offset<Self>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::core::option::Option<::planus::Offset<PoseWithCovariance>> {
+                ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+            }
+        }
+
+        impl ::planus::WriteAsOffset<PoseWithCovariance> for PoseWithCovariance {
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::planus::Offset<PoseWithCovariance> {
+                PoseWithCovariance::create(builder, self.pose, &self.covariance)
+            }
+        }
+
+        /// Builder for serializing an instance of the [PoseWithCovariance] type.
+        ///
+        /// Can be created using the [PoseWithCovariance::builder] method.
+        #[derive(Debug)]
+        #[must_use]
+        pub struct PoseWithCovarianceBuilder<State>(State);
+
+        impl PoseWithCovarianceBuilder<()> {
+            /// Setter for the [`pose` field](PoseWithCovariance#structfield.pose).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn pose<T0>(self, value: T0) -> PoseWithCovarianceBuilder<(T0,)>
+            where
+                T0: ::planus::WriteAsOptional<self::Pose>,
+            {
+                PoseWithCovarianceBuilder((value,))
+            }
+
+            /// Sets the [`pose` field](PoseWithCovariance#structfield.pose) to null.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn pose_as_null(self) -> PoseWithCovarianceBuilder<((),)> {
+                self.pose(())
+            }
+        }
+
+        impl<T0> PoseWithCovarianceBuilder<(T0,)> {
+            /// Setter for the [`covariance` field](PoseWithCovariance#structfield.covariance).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn covariance<T1>(self, value: T1) -> PoseWithCovarianceBuilder<(T0, T1)>
+            where
+                T1: ::planus::WriteAsOptional<::planus::Offset<[f64]>>,
+            {
+                let (v0,) = self.0;
+                PoseWithCovarianceBuilder((v0, value))
+            }
+
+            /// Sets the [`covariance` field](PoseWithCovariance#structfield.covariance) to null.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn covariance_as_null(self) -> PoseWithCovarianceBuilder<(T0, ())> {
+                self.covariance(())
+            }
+        }
+
+        impl<T0, T1> PoseWithCovarianceBuilder<(T0, T1)> {
+            /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [PoseWithCovariance].
+            #[inline]
+            pub fn finish(
+                self,
+                builder: &mut ::planus::Builder,
+            ) -> ::planus::Offset<PoseWithCovariance>
+            where
+                Self: ::planus::WriteAsOffset<PoseWithCovariance>,
+            {
+                ::planus::WriteAsOffset::prepare(&self, builder)
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsOptional<self::Pose>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<[f64]>>,
+            > ::planus::WriteAs<::planus::Offset<PoseWithCovariance>>
+            for PoseWithCovarianceBuilder<(T0, T1)>
+        {
+            type Prepared = ::planus::Offset<PoseWithCovariance>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::planus::Offset<PoseWithCovariance> {
+                ::planus::WriteAsOffset::prepare(self, builder)
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsOptional<self::Pose>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<[f64]>>,
+            > ::planus::WriteAsOptional<::planus::Offset<PoseWithCovariance>>
+            for PoseWithCovarianceBuilder<(T0, T1)>
+        {
+            type Prepared = ::planus::Offset<PoseWithCovariance>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::core::option::Option<::planus::Offset<PoseWithCovariance>> {
+                ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsOptional<self::Pose>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<[f64]>>,
+            > ::planus::WriteAsOffset<PoseWithCovariance> for PoseWithCovarianceBuilder<(T0, T1)>
+        {
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::planus::Offset<PoseWithCovariance> {
+                let (v0, v1) = &self.0;
+                PoseWithCovariance::create(builder, v0, v1)
+            }
+        }
+
+        /// Reference to a deserialized [PoseWithCovariance].
+        #[derive(Copy, Clone)]
+        pub struct PoseWithCovarianceRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+        impl<'a> PoseWithCovarianceRef<'a> {
+            /// Getter for the [`pose` field](PoseWithCovariance#structfield.pose).
+            #[inline]
+            pub fn pose(&self) -> ::planus::Result<::core::option::Option<self::PoseRef<'a>>> {
+                self.0.access(0, "PoseWithCovariance", "pose")
+            }
+
+            /// Getter for the [`covariance` field](PoseWithCovariance#structfield.covariance).
+            #[inline]
+            pub fn covariance(
+                &self,
+            ) -> ::planus::Result<::core::option::Option<::planus::Vector<'a, f64>>> {
+                self.0.access(1, "PoseWithCovariance", "covariance")
+            }
+        }
+
+        impl<'a> ::core::fmt::Debug for PoseWithCovarianceRef<'a> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut f = f.debug_struct("PoseWithCovarianceRef");
+                if let ::core::option::Option::Some(field_pose) = self.pose().transpose() {
+                    f.field("pose", &field_pose);
+                }
+                if let ::core::option::Option::Some(field_covariance) =
+                    self.covariance().transpose()
+                {
+                    f.field("covariance", &field_covariance);
+                }
+                f.finish()
+            }
+        }
+
+        impl<'a> ::core::convert::TryFrom<PoseWithCovarianceRef<'a>> for PoseWithCovariance {
+            type Error = ::planus::Error;
+
+            #[allow(unreachable_code)]
+            fn try_from(value: PoseWithCovarianceRef<'a>) -> ::planus::Result<Self> {
+                ::core::result::Result::Ok(Self {
+                    pose: value.pose()?.map(::core::convert::Into::into),
+                    covariance: if let ::core::option::Option::Some(covariance) =
+                        value.covariance()?
+                    {
+                        ::core::option::Option::Some(covariance.to_vec()?)
+                    } else {
+                        ::core::option::Option::None
+                    },
+                })
+            }
+        }
+
+        impl<'a> ::planus::TableRead<'a> for PoseWithCovarianceRef<'a> {
+            #[inline]
+            fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                    buffer, offset,
+                )?))
+            }
+        }
+
+        impl<'a> ::planus::VectorReadInner<'a> for PoseWithCovarianceRef<'a> {
+            type Error = ::planus::Error;
+            const STRIDE: usize = 4;
+
+            unsafe fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::planus::Result<Self> {
+                ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                    error_kind.with_error_location(
+                        "[PoseWithCovarianceRef]",
+                        "get",
+                        buffer.offset_from_start,
+                    )
+                })
+            }
+        }
+
+        /// # Safety
+        /// The planus compiler generates implementations that initialize
+        /// the bytes in `write_values`.
+        unsafe impl ::planus::VectorWrite<::planus::Offset<PoseWithCovariance>> for PoseWithCovariance {
+            type Value = ::planus::Offset<PoseWithCovariance>;
+            const STRIDE: usize = 4;
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                ::planus::WriteAs::prepare(self, builder)
+            }
+
+            #[inline]
+            unsafe fn write_values(
+                values: &[::planus::Offset<PoseWithCovariance>],
+                bytes: *mut ::core::mem::MaybeUninit<u8>,
+                buffer_position: u32,
+            ) {
+                let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                    ::planus::WriteAsPrimitive::write(
+                        v,
+                        ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                        buffer_position - (Self::STRIDE * i) as u32,
+                    );
+                }
+            }
+        }
+
+        impl<'a> ::planus::ReadAsRoot<'a> for PoseWithCovarianceRef<'a> {
+            fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                ::planus::TableRead::from_buffer(
+                    ::planus::SliceWithStartOffset {
+                        buffer: slice,
+                        offset_from_start: 0,
+                    },
+                    0,
+                )
+                .map_err(|error_kind| {
+                    error_kind.with_error_location("[PoseWithCovarianceRef]", "read_as_root", 0)
+                })
+            }
+        }
+
+        /// The table `StampedPose` in the namespace `rbt3`
+        ///
+        /// Generated from these locations:
+        /// * Table `StampedPose` in the file `proto/rbt3.fbs:37`
+        #[derive(Clone, Debug, PartialEq, PartialOrd)]
+        pub struct StampedPose {
+            /// The field `time` in the table `StampedPose`
+            pub time: f64,
+            /// The field `frame` in the table `StampedPose`
+            pub frame: ::core::option::Option<::planus::alloc::string::String>,
+            /// The field `child_frame` in the table `StampedPose`
+            pub child_frame: ::core::option::Option<::planus::alloc::string::String>,
+            /// The field `pose` in the table `StampedPose`
+            pub pose: ::core::option::Option<self::Pose>,
+        }
+
+        #[allow(clippy::derivable_impls)]
+        impl ::core::default::Default for StampedPose {
+            fn default() -> Self {
+                Self {
+                    time: 0.0,
+                    frame: ::core::default::Default::default(),
+                    child_frame: ::core::default::Default::default(),
+                    pose: ::core::default::Default::default(),
+                }
+            }
+        }
+
+        impl StampedPose {
+            /// Creates a [StampedPoseBuilder] for serializing an instance of this table.
+            #[inline]
+            pub fn builder() -> StampedPoseBuilder<()> {
+                StampedPoseBuilder(())
+            }
+
+            #[allow(clippy::too_many_arguments)]
+            pub fn create(
+                builder: &mut ::planus::Builder,
+                field_time: impl ::planus::WriteAsDefault<f64, f64>,
+                field_frame: impl ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                field_child_frame: impl ::planus::WriteAsOptional<
+                    ::planus::Offset<::core::primitive::str>,
+                >,
+                field_pose: impl ::planus::WriteAsOptional<self::Pose>,
+            ) -> ::planus::Offset<Self> {
+                let prepared_time = field_time.prepare(builder, &0.0);
+                let prepared_frame = field_frame.prepare(builder);
+                let prepared_child_frame = field_child_frame.prepare(builder);
+                let prepared_pose = field_pose.prepare(builder);
+
+                let mut table_writer: ::planus::table_writer::TableWriter<12> =
+                    ::core::default::Default::default();
+                if prepared_time.is_some() {
+                    table_writer.write_entry::<f64>(0);
+                }
+                if prepared_pose.is_some() {
+                    table_writer.write_entry::<self::Pose>(3);
+                }
+                if prepared_frame.is_some() {
+                    table_writer.write_entry::<::planus::Offset<str>>(1);
+                }
+                if prepared_child_frame.is_some() {
+                    table_writer.write_entry::<::planus::Offset<str>>(2);
+                }
+
+                unsafe {
+                    table_writer.finish(builder, |object_writer| {
+                        if let ::core::option::Option::Some(prepared_time) = prepared_time {
+                            object_writer.write::<_, _, 8>(&prepared_time);
+                        }
+                        if let ::core::option::Option::Some(prepared_pose) = prepared_pose {
+                            object_writer.write::<_, _, 56>(&prepared_pose);
+                        }
+                        if let ::core::option::Option::Some(prepared_frame) = prepared_frame {
+                            object_writer.write::<_, _, 4>(&prepared_frame);
+                        }
+                        if let ::core::option::Option::Some(prepared_child_frame) =
+                            prepared_child_frame
+                        {
+                            object_writer.write::<_, _, 4>(&prepared_child_frame);
+                        }
+                    });
+                }
+                builder.current_offset()
+            }
+        }
+
+        impl ::planus::WriteAs<::planus::Offset<StampedPose>> for StampedPose {
+            type Prepared = ::planus::Offset<Self>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<StampedPose> {
+                ::planus::WriteAsOffset::prepare(self, builder)
+            }
+        }
+
+        impl ::planus::WriteAsOptional<::planus::Offset<StampedPose>> for StampedPose {
+            type Prepared = ::planus::Offset<Self>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::core::option::Option<::planus::Offset<StampedPose>> {
+                ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+            }
+        }
+
+        impl ::planus::WriteAsOffset<StampedPose> for StampedPose {
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<StampedPose> {
+                StampedPose::create(
+                    builder,
+                    self.time,
+                    &self.frame,
+                    &self.child_frame,
+                    self.pose,
+                )
+            }
+        }
+
+        /// Builder for serializing an instance of the [StampedPose] type.
+        ///
+        /// Can be created using the [StampedPose::builder] method.
+        #[derive(Debug)]
+        #[must_use]
+        pub struct StampedPoseBuilder<State>(State);
+
+        impl StampedPoseBuilder<()> {
+            /// Setter for the [`time` field](StampedPose#structfield.time).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn time<T0>(self, value: T0) -> StampedPoseBuilder<(T0,)>
+            where
+                T0: ::planus::WriteAsDefault<f64, f64>,
+            {
+                StampedPoseBuilder((value,))
+            }
+
+            /// Sets the [`time` field](StampedPose#structfield.time) to the default value.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn time_as_default(self) -> StampedPoseBuilder<(::planus::DefaultValue,)> {
+                self.time(::planus::DefaultValue)
+            }
+        }
+
+        impl<T0> StampedPoseBuilder<(T0,)> {
+            /// Setter for the [`frame` field](StampedPose#structfield.frame).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn frame<T1>(self, value: T1) -> StampedPoseBuilder<(T0, T1)>
+            where
+                T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+            {
+                let (v0,) = self.0;
+                StampedPoseBuilder((v0, value))
+            }
+
+            /// Sets the [`frame` field](StampedPose#structfield.frame) to null.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn frame_as_null(self) -> StampedPoseBuilder<(T0, ())> {
+                self.frame(())
+            }
+        }
+
+        impl<T0, T1> StampedPoseBuilder<(T0, T1)> {
+            /// Setter for the [`child_frame` field](StampedPose#structfield.child_frame).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn child_frame<T2>(self, value: T2) -> StampedPoseBuilder<(T0, T1, T2)>
+            where
+                T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+            {
+                let (v0, v1) = self.0;
+                StampedPoseBuilder((v0, v1, value))
+            }
+
+            /// Sets the [`child_frame` field](StampedPose#structfield.child_frame) to null.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn child_frame_as_null(self) -> StampedPoseBuilder<(T0, T1, ())> {
+                self.child_frame(())
+            }
+        }
+
+        impl<T0, T1, T2> StampedPoseBuilder<(T0, T1, T2)> {
+            /// Setter for the [`pose` field](StampedPose#structfield.pose).
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn pose<T3>(self, value: T3) -> StampedPoseBuilder<(T0, T1, T2, T3)>
+            where
+                T3: ::planus::WriteAsOptional<self::Pose>,
+            {
+                let (v0, v1, v2) = self.0;
+                StampedPoseBuilder((v0, v1, v2, value))
+            }
+
+            /// Sets the [`pose` field](StampedPose#structfield.pose) to null.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            pub fn pose_as_null(self) -> StampedPoseBuilder<(T0, T1, T2, ())> {
+                self.pose(())
+            }
+        }
+
+        impl<T0, T1, T2, T3> StampedPoseBuilder<(T0, T1, T2, T3)> {
+            /// Finish writing the builder to get an [Offset](::planus::Offset) to a serialized [StampedPose].
+            #[inline]
+            pub fn finish(self, builder: &mut ::planus::Builder) -> ::planus::Offset<StampedPose>
+            where
+                Self: ::planus::WriteAsOffset<StampedPose>,
+            {
+                ::planus::WriteAsOffset::prepare(&self, builder)
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsDefault<f64, f64>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T3: ::planus::WriteAsOptional<self::Pose>,
+            > ::planus::WriteAs<::planus::Offset<StampedPose>>
+            for StampedPoseBuilder<(T0, T1, T2, T3)>
+        {
+            type Prepared = ::planus::Offset<StampedPose>;
+
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<StampedPose> {
+                ::planus::WriteAsOffset::prepare(self, builder)
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsDefault<f64, f64>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T3: ::planus::WriteAsOptional<self::Pose>,
+            > ::planus::WriteAsOptional<::planus::Offset<StampedPose>>
+            for StampedPoseBuilder<(T0, T1, T2, T3)>
+        {
+            type Prepared = ::planus::Offset<StampedPose>;
+
+            #[inline]
+            fn prepare(
+                &self,
+                builder: &mut ::planus::Builder,
+            ) -> ::core::option::Option<::planus::Offset<StampedPose>> {
+                ::core::option::Option::Some(::planus::WriteAsOffset::prepare(self, builder))
+            }
+        }
+
+        impl<
+                T0: ::planus::WriteAsDefault<f64, f64>,
+                T1: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T2: ::planus::WriteAsOptional<::planus::Offset<::core::primitive::str>>,
+                T3: ::planus::WriteAsOptional<self::Pose>,
+            > ::planus::WriteAsOffset<StampedPose> for StampedPoseBuilder<(T0, T1, T2, T3)>
+        {
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> ::planus::Offset<StampedPose> {
+                let (v0, v1, v2, v3) = &self.0;
+                StampedPose::create(builder, v0, v1, v2, v3)
+            }
+        }
+
+        /// Reference to a deserialized [StampedPose].
+        #[derive(Copy, Clone)]
+        pub struct StampedPoseRef<'a>(#[allow(dead_code)] ::planus::table_reader::Table<'a>);
+
+        impl<'a> StampedPoseRef<'a> {
+            /// Getter for the [`time` field](StampedPose#structfield.time).
+            #[inline]
+            pub fn time(&self) -> ::planus::Result<f64> {
+                ::core::result::Result::Ok(self.0.access(0, "StampedPose", "time")?.unwrap_or(0.0))
+            }
+
+            /// Getter for the [`frame` field](StampedPose#structfield.frame).
+            #[inline]
+            pub fn frame(
+                &self,
+            ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>> {
+                self.0.access(1, "StampedPose", "frame")
+            }
+
+            /// Getter for the [`child_frame` field](StampedPose#structfield.child_frame).
+            #[inline]
+            pub fn child_frame(
+                &self,
+            ) -> ::planus::Result<::core::option::Option<&'a ::core::primitive::str>> {
+                self.0.access(2, "StampedPose", "child_frame")
+            }
+
+            /// Getter for the [`pose` field](StampedPose#structfield.pose).
+            #[inline]
+            pub fn pose(&self) -> ::planus::Result<::core::option::Option<self::PoseRef<'a>>> {
+                self.0.access(3, "StampedPose", "pose")
+            }
+        }
+
+        impl<'a> ::core::fmt::Debug for StampedPoseRef<'a> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut f = f.debug_struct("StampedPoseRef");
+                f.field("time", &self.time());
+                if let ::core::option::Option::Some(field_frame) = self.frame().transpose() {
+                    f.field("frame", &field_frame);
+                }
+                if let ::core::option::Option::Some(field_child_frame) =
+                    self.child_frame().transpose()
+                {
+                    f.field("child_frame", &field_child_frame);
+                }
+                if let ::core::option::Option::Some(field_pose) = self.pose().transpose() {
+                    f.field("pose", &field_pose);
+                }
+                f.finish()
+            }
+        }
+
+        impl<'a> ::core::convert::TryFrom<StampedPoseRef<'a>> for StampedPose {
+            type Error = ::planus::Error;
+
+            #[allow(unreachable_code)]
+            fn try_from(value: StampedPoseRef<'a>) -> ::planus::Result<Self> {
+                ::core::result::Result::Ok(Self {
+                    time: ::core::convert::TryInto::try_into(value.time()?)?,
+                    frame: value.frame()?.map(::core::convert::Into::into),
+                    child_frame: value.child_frame()?.map(::core::convert::Into::into),
+                    pose: value.pose()?.map(::core::convert::Into::into),
+                })
+            }
+        }
+
+        impl<'a> ::planus::TableRead<'a> for StampedPoseRef<'a> {
+            #[inline]
+            fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::planus::errors::ErrorKind> {
+                ::core::result::Result::Ok(Self(::planus::table_reader::Table::from_buffer(
+                    buffer, offset,
+                )?))
+            }
+        }
+
+        impl<'a> ::planus::VectorReadInner<'a> for StampedPoseRef<'a> {
+            type Error = ::planus::Error;
+            const STRIDE: usize = 4;
+
+            unsafe fn from_buffer(
+                buffer: ::planus::SliceWithStartOffset<'a>,
+                offset: usize,
+            ) -> ::planus::Result<Self> {
+                ::planus::TableRead::from_buffer(buffer, offset).map_err(|error_kind| {
+                    error_kind.with_error_location(
+                        "[StampedPoseRef]",
+                        "get",
+                        buffer.offset_from_start,
+                    )
+                })
+            }
+        }
+
+        /// # Safety
+        /// The planus compiler generates implementations that initialize
+        /// the bytes in `write_values`.
+        unsafe impl ::planus::VectorWrite<::planus::Offset<StampedPose>> for StampedPose {
+            type Value = ::planus::Offset<StampedPose>;
+            const STRIDE: usize = 4;
+            #[inline]
+            fn prepare(&self, builder: &mut ::planus::Builder) -> Self::Value {
+                ::planus::WriteAs::prepare(self, builder)
+            }
+
+            #[inline]
+            unsafe fn write_values(
+                values: &[::planus::Offset<StampedPose>],
+                bytes: *mut ::core::mem::MaybeUninit<u8>,
+                buffer_position: u32,
+            ) {
+                let bytes = bytes as *mut [::core::mem::MaybeUninit<u8>; 4];
+                for (i, v) in ::core::iter::Iterator::enumerate(values.iter()) {
+                    ::planus::WriteAsPrimitive::write(
+                        v,
+                        ::planus::Cursor::new(unsafe { &mut *bytes.add(i) }),
+                        buffer_position - (Self::STRIDE * i) as u32,
+                    );
+                }
+            }
+        }
+
+        impl<'a> ::planus::ReadAsRoot<'a> for StampedPoseRef<'a> {
+            fn read_as_root(slice: &'a [u8]) -> ::planus::Result<Self> {
+                ::planus::TableRead::from_buffer(
+                    ::planus::SliceWithStartOffset {
+                        buffer: slice,
+                        offset_from_start: 0,
+                    },
+                    0,
+                )
+                .map_err(|error_kind| {
+                    error_kind.with_error_location("[StampedPoseRef]", "read_as_root", 0)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Transforms tagged with their frames at the type level.
+
+use crate::{Rbt, Vec3};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Mul;
+
+/// A transform from frame `To` to frame `From`, i.e. the pose of `To` expressed in `From`.
+///
+/// Frames are arbitrary marker types. Composition only compiles when the inner frames match:
+/// `FramedRbt<A, B> * FramedRbt<B, C>` is a `FramedRbt<A, C>`.
+pub struct FramedRbt<From, To> {
+    rbt: Rbt,
+    frames: PhantomData<fn() -> (From, To)>,
+}
+
+impl<From, To> FramedRbt<From, To> {
+    /// Tags an untyped transform with frames.
+    pub fn new(rbt: Rbt) -> Self {
+        FramedRbt {
+            rbt,
+            frames: PhantomData,
+        }
+    }
+
+    /// Returns the untyped transform.
+    pub fn rbt(&self) -> &Rbt {
+        &self.rbt
+    }
+
+    /// Converts to the untyped transform.
+    pub fn into_rbt(self) -> Rbt {
+        self.rbt
+    }
+
+    /// Returns the inverse transform, from `From` to `To`.
+    pub fn inverse(&self) -> FramedRbt<To, From> {
+        FramedRbt::new(self.rbt.inverse())
+    }
+
+    /// Transforms a point from `To` coordinates to `From` coordinates.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rbt.rotation * point + self.rbt.translation
+    }
+
+    /// Transforms a vector from `To` coordinates to `From` coordinates.
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rbt.rotation * vector
+    }
+}
+
+impl<From, To> fmt::Debug for FramedRbt<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FramedRbt")
+            .field("from", &core::any::type_name::<From>())
+            .field("to", &core::any::type_name::<To>())
+            .field("rbt", &self.rbt)
+            .finish()
+    }
+}
+
+impl<From, To> Clone for FramedRbt<From, To> {
+    fn clone(&self) -> Self {
+        FramedRbt::new(self.rbt.clone())
+    }
+}
+
+impl<From, To> PartialEq for FramedRbt<From, To> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rbt == other.rbt
+    }
+}
+
+impl<From, To> Default for FramedRbt<From, To> {
+    fn default() -> Self {
+        FramedRbt::new(Rbt::default())
+    }
+}
+
+impl<A, B, C> Mul<&FramedRbt<B, C>> for &FramedRbt<A, B> {
+    type Output = FramedRbt<A, C>;
+
+    fn mul(self, rhs: &FramedRbt<B, C>) -> FramedRbt<A, C> {
+        FramedRbt::new(&self.rbt * &rhs.rbt)
+    }
+}
+
+impl<A, B, C> Mul<FramedRbt<B, C>> for &FramedRbt<A, B> {
+    type Output = FramedRbt<A, C>;
+
+    fn mul(self, rhs: FramedRbt<B, C>) -> FramedRbt<A, C> {
+        self * &rhs
+    }
+}
+
+impl<A, B, C> Mul<&FramedRbt<B, C>> for FramedRbt<A, B> {
+    type Output = FramedRbt<A, C>;
+
+    fn mul(self, rhs: &FramedRbt<B, C>) -> FramedRbt<A, C> {
+        &self * rhs
+    }
+}
+
+impl<A, B, C> Mul<FramedRbt<B, C>> for FramedRbt<A, B> {
+    type Output = FramedRbt<A, C>;
+
+    fn mul(self, rhs: FramedRbt<B, C>) -> FramedRbt<A, C> {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,142 @@
+//! Gauss-Newton refinement of a single pose.
+
+use crate::jacobian::{perturb, Perturbation};
+use crate::{linalg, Rbt, Twist};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Settings of a damped Gauss-Newton (Levenberg-Marquardt) pose refinement.
+pub struct GaussNewton {
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+    /// The initial damping added to the diagonal of the normal equations. 0 for plain Gauss-Newton.
+    pub damping: f32,
+    /// Stops when the step norm falls below this.
+    pub step_tolerance: f32,
+    /// Stops when the relative cost decrease falls below this.
+    pub cost_tolerance: f32,
+    /// The side the pose update is applied on.
+    pub perturbation: Perturbation,
+    /// Step of the central differences when Jacobians are numeric.
+    pub numeric_eps: f32,
+}
+
+impl Default for GaussNewton {
+    fn default() -> Self {
+        GaussNewton {
+            max_iterations: 50,
+            damping: 1e-3,
+            step_tolerance: 1e-6,
+            cost_tolerance: 1e-9,
+            perturbation: Perturbation::Right,
+            numeric_eps: 1e-3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of a refinement.
+pub struct Refinement {
+    /// The refined pose.
+    pub rbt: Rbt,
+    /// Half the squared norm of the residual at `rbt`.
+    pub cost: f32,
+    /// The number of iterations run.
+    pub iterations: usize,
+    /// Whether a tolerance was reached before `max_iterations`.
+    pub converged: bool,
+}
+
+impl GaussNewton {
+    /// Refines `initial` minimizing the squared norm of `residual`, with numeric Jacobians.
+    pub fn solve(&self, initial: &Rbt, residual: impl Fn(&Rbt) -> Vec<f32>) -> Refinement {
+        self.solve_with_jacobian(initial, |rbt| {
+            let r = residual(rbt);
+            let mut j = vec![[0.0; 6]; r.len()];
+            for k in 0..6 {
+                let mut delta = [0.0; 6];
+                delta[k] = self.numeric_eps;
+                let delta = Twist::from_array(delta);
+                let plus = residual(&perturb(rbt, &delta, self.perturbation));
+                let minus = residual(&perturb(rbt, &-delta, self.perturbation));
+                for (row, (p, m)) in j.iter_mut().zip(plus.iter().zip(&minus)) {
+                    row[k] = (p - m) / (2.0 * self.numeric_eps);
+                }
+            }
+            (r, j)
+        })
+    }
+
+    /// Refines `initial` minimizing the squared norm of a residual.
+    ///
+    /// `residual_jacobian` returns the residual and its Jacobian rows with respect to `self.perturbation`.
+    pub fn solve_with_jacobian(
+        &self,
+        initial: &Rbt,
+        residual_jacobian: impl Fn(&Rbt) -> (Vec<f32>, Vec<[f32; 6]>),
+    ) -> Refinement {
+        let cost_of = |r: &[f32]| r.iter().map(|x| (*x as f64) * (*x as f64)).sum::<f64>() * 0.5;
+
+        let mut rbt = initial.clone();
+        let (mut r, mut j) = residual_jacobian(&rbt);
+        let mut cost = cost_of(&r);
+        let mut lambda = self.damping as f64;
+        let mut iterations = 0;
+        let mut converged = false;
+
+        while iterations < self.max_iterations {
+            iterations += 1;
+
+            let mut h = [[0.0f64; 6]; 6];
+            let mut g = [0.0f64; 6];
+            for (ri, ji) in r.iter().zip(&j) {
+                for a in 0..6 {
+                    g[a] -= ji[a] as f64 * *ri as f64;
+                    for b in 0..6 {
+                        h[a][b] += ji[a] as f64 * ji[b] as f64;
+                    }
+                }
+            }
+            let mut damped = h;
+            for (a, row) in damped.iter_mut().enumerate() {
+                row[a] += lambda * h[a][a].max(1e-12);
+            }
+            let Some(step) = linalg::solve_spd(&damped, &g) else {
+                lambda = lambda.max(1e-6) * 10.0;
+                continue;
+            };
+
+            let delta = Twist::from_array(step.map(|x| x as f32));
+            let candidate = perturb(&rbt, &delta, self.perturbation);
+            let (r1, j1) = residual_jacobian(&candidate);
+            let cost1 = cost_of(&r1);
+
+            if cost1 <= cost {
+                let decrease = cost - cost1;
+                rbt = candidate;
+                r = r1;
+                j = j1;
+                cost = cost1;
+                lambda *= 0.1;
+                if delta.length() < self.step_tolerance
+                    || decrease <= self.cost_tolerance as f64 * cost.max(f64::MIN_POSITIVE)
+                {
+                    converged = true;
+                    break;
+                }
+            } else {
+                lambda = lambda.max(1e-6) * 10.0;
+                if delta.length() < self.step_tolerance {
+                    converged = true;
+                    break;
+                }
+            }
+        }
+
+        Refinement {
+            rbt,
+            cost: cost as f32,
+            iterations,
+            converged,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! WGS-84 geodetic coordinates and local tangent frames.
+//!
+//! Computed in `f64`, since earth-centered coordinates don't fit `f32` precision.
+
+use crate::{math, Mat3, Quat, Rbt, Vec3};
+
+/// The WGS-84 semi-major axis in meters.
+pub const WGS84_A: f64 = 6_378_137.0;
+
+/// The WGS-84 flattening.
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// The square of the WGS-84 first eccentricity.
+const E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// A position on the WGS-84 ellipsoid.
+pub struct Geodetic {
+    /// Latitude in degrees, positive north.
+    pub latitude: f64,
+    /// Longitude in degrees, positive east.
+    pub longitude: f64,
+    /// Height above the ellipsoid in meters.
+    pub altitude: f64,
+}
+
+impl Geodetic {
+    /// Creates a position from degrees and meters.
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Geodetic {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Converts to earth-centered, earth-fixed coordinates in meters.
+    pub fn to_ecef(&self) -> [f64; 3] {
+        let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (sin_lat, cos_lat) = (math::sin_f64(lat), math::cos_f64(lat));
+        let (sin_lon, cos_lon) = (math::sin_f64(lon), math::cos_f64(lon));
+        let n = WGS84_A / math::sqrt_f64(1.0 - E2 * sin_lat * sin_lat);
+        [
+            (n + self.altitude) * cos_lat * cos_lon,
+            (n + self.altitude) * cos_lat * sin_lon,
+            (n * (1.0 - E2) + self.altitude) * sin_lat,
+        ]
+    }
+
+    /// Converts from earth-centered, earth-fixed coordinates in meters.
+    pub fn from_ecef(ecef: [f64; 3]) -> Self {
+        let [x, y, z] = ecef;
+        let p = math::hypot_f64(x, y);
+        let mut lat = math::atan2_f64(z, p * (1.0 - E2));
+        for _ in 0..8 {
+            let sin_lat = math::sin_f64(lat);
+            let n = WGS84_A / math::sqrt_f64(1.0 - E2 * sin_lat * sin_lat);
+            lat = math::atan2_f64(z + E2 * n * sin_lat, p);
+        }
+        let (sin_lat, cos_lat) = (math::sin_f64(lat), math::cos_f64(lat));
+        let altitude =
+            p * cos_lat + z * sin_lat - WGS84_A * math::sqrt_f64(1.0 - E2 * sin_lat * sin_lat);
+        Geodetic {
+            latitude: lat.to_degrees(),
+            longitude: math::atan2_f64(y, x).to_degrees(),
+            altitude,
+        }
+    }
+
+    /// Returns the rows of the rotation from earth-centered, earth-fixed axes to east, north, up axes here.
+    fn enu_rows(&self) -> [[f64; 3]; 3] {
+        let (lat, lon) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (sin_lat, cos_lat) = (math::sin_f64(lat), math::cos_f64(lat));
+        let (sin_lon, cos_lon) = (math::sin_f64(lon), math::cos_f64(lon));
+        [
+            [-sin_lon, cos_lon, 0.0],
+            [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+            [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The axes of a local tangent frame.
+pub enum Tangent {
+    /// East, north, up. Bodies are x forward, y left, z up.
+    Enu,
+    /// North, east, down. Bodies are x forward, y right, z down.
+    Ned,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A local tangent frame anchored at a geodetic origin.
+pub struct LocalFrame {
+    origin: Geodetic,
+    origin_ecef: [f64; 3],
+    tangent: Tangent,
+    /// The rotation from earth-centered, earth-fixed axes to the local axes, as rows.
+    rows: [[f64; 3]; 3],
+}
+
+impl LocalFrame {
+    /// Creates the tangent frame at `origin`.
+    pub fn new(origin: Geodetic, tangent: Tangent) -> Self {
+        LocalFrame {
+            origin,
+            origin_ecef: origin.to_ecef(),
+            tangent,
+            rows: tangent_rows(&origin, tangent),
+        }
+    }
+
+    /// Returns the origin.
+    pub fn origin(&self) -> &Geodetic {
+        &self.origin
+    }
+
+    /// Returns the axes.
+    pub fn tangent(&self) -> Tangent {
+        self.tangent
+    }
+
+    /// Converts a geodetic position to local coordinates in meters.
+    pub fn to_local(&self, position: &Geodetic) -> Vec3 {
+        let ecef = position.to_ecef();
+        let d = [0, 1, 2].map(|i| ecef[i] - self.origin_ecef[i]);
+        Vec3::from(
+            self.rows
+                .map(|row| (row[0] * d[0] + row[1] * d[1] + row[2] * d[2]) as f32),
+        )
+    }
+
+    /// Converts local coordinates in meters to a geodetic position.
+    pub fn to_geodetic(&self, local: Vec3) -> Geodetic {
+        let l = local.as_dvec3().to_array();
+        let r = &self.rows;
+        Geodetic::from_ecef(
+            [0, 1, 2]
+                .map(|i| self.origin_ecef[i] + r[0][i] * l[0] + r[1][i] * l[1] + r[2][i] * l[2]),
+        )
+    }
+
+    /// Returns the pose of a body at `position` with `heading` in radians, clockwise from north, and level.
+    ///
+    /// Heading is relative to north at `position`, which differs from north at the origin far away from it.
+    pub fn pose(&self, position: &Geodetic, heading: f32) -> Rbt {
+        let yaw = match self.tangent {
+            Tangent::Enu => Quat::from_rotation_z(std::f32::consts::FRAC_PI_2 - heading),
+            Tangent::Ned => Quat::from_rotation_z(heading),
+        };
+        // The rotation from the tangent frame at `position` to the one at the origin.
+        let fix = tangent_rows(position, self.tangent);
+        let cols =
+            [0, 1, 2].map(|j| {
+                Vec3::from(self.rows.map(|row| {
+                    (row[0] * fix[j][0] + row[1] * fix[j][1] + row[2] * fix[j][2]) as f32
+                }))
+            });
+        let tilt = Quat::from_mat3(&Mat3::from_cols(cols[0], cols[1], cols[2]));
+        Rbt::from_t_r(self.to_local(position), (tilt * yaw).normalize())
+    }
+}
+
+/// Returns the rows of the rotation from earth-centered, earth-fixed axes to `tangent` axes at `origin`.
+fn tangent_rows(origin: &Geodetic, tangent: Tangent) -> [[f64; 3]; 3] {
+    let [e, n, u] = origin.enu_rows();
+    match tangent {
+        Tangent::Enu => [e, n, u],
+        Tangent::Ned => [n, e, u.map(|x| -x)],
+    }
+}
@@ -0,0 +1,118 @@
+//! Geometric queries for translate and rotate gizmos.
+//!
+//! Rays are given in world space.
+//! Drag functions take the pose and picking ray at the start of the drag and the current picking ray,
+//! and return the updated pose.
+
+use crate::{math, Quat, Ray, Rbt, Vec3};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A local axis of a transform.
+pub enum Axis {
+    /// The local X axis.
+    X,
+    /// The local Y axis.
+    Y,
+    /// The local Z axis.
+    Z,
+}
+
+impl Axis {
+    /// Returns the unit vector of this axis.
+    pub fn unit(self) -> Vec3 {
+        match self {
+            Axis::X => Vec3::X,
+            Axis::Y => Vec3::Y,
+            Axis::Z => Vec3::Z,
+        }
+    }
+
+    /// Returns this axis of `rbt` in world space.
+    pub fn of(self, rbt: &Rbt) -> Vec3 {
+        rbt.rotation * self.unit()
+    }
+}
+
+/// Returns the parameter along the line `origin + s * dir` of the point closest to `ray`.
+///
+/// Returns `None` if the line and the ray are parallel.
+pub fn closest_point_on_axis(origin: Vec3, dir: Vec3, ray: &Ray) -> Option<f32> {
+    let w = origin - ray.origin;
+    let a = dir.dot(dir);
+    let b = dir.dot(ray.dir);
+    let c = ray.dir.dot(ray.dir);
+    let denom = a * c - b * b;
+    if denom.abs() <= f32::EPSILON * a * c {
+        return None;
+    }
+    Some((b * ray.dir.dot(w) - c * dir.dot(w)) / denom)
+}
+
+/// Intersects `ray` with the plane through `point` with normal `normal`.
+///
+/// Returns `None` if the ray is parallel to the plane or the plane is behind the ray.
+pub fn ray_plane(point: Vec3, normal: Vec3, ray: &Ray) -> Option<Vec3> {
+    let denom = ray.dir.dot(normal);
+    if denom.abs() <= f32::EPSILON {
+        return None;
+    }
+    let t = (point - ray.origin).dot(normal) / denom;
+    if t < 0.0 {
+        return None;
+    }
+    Some(ray.at(t))
+}
+
+/// Intersects `ray` with the local plane of `rbt` perpendicular to `normal`.
+pub fn ray_local_plane(rbt: &Rbt, normal: Axis, ray: &Ray) -> Option<Vec3> {
+    ray_plane(rbt.translation, normal.of(rbt), ray)
+}
+
+/// Translates `start` along its local `axis`, following the picking ray from `start_ray` to `ray`.
+///
+/// Returns `start` unchanged if either ray is parallel to the axis.
+pub fn drag_translate_axis(start: &Rbt, axis: Axis, start_ray: &Ray, ray: &Ray) -> Rbt {
+    let dir = axis.of(start);
+    match (
+        closest_point_on_axis(start.translation, dir, start_ray),
+        closest_point_on_axis(start.translation, dir, ray),
+    ) {
+        (Some(s0), Some(s1)) => Rbt::from_t_r(start.translation + dir * (s1 - s0), start.rotation),
+        _ => start.clone(),
+    }
+}
+
+/// Translates `start` in its local plane perpendicular to `normal`, following the picking ray from `start_ray` to `ray`.
+///
+/// Returns `start` unchanged if either ray misses the plane.
+pub fn drag_translate_plane(start: &Rbt, normal: Axis, start_ray: &Ray, ray: &Ray) -> Rbt {
+    match (
+        ray_local_plane(start, normal, start_ray),
+        ray_local_plane(start, normal, ray),
+    ) {
+        (Some(p0), Some(p1)) => Rbt::from_t_r(start.translation + (p1 - p0), start.rotation),
+        _ => start.clone(),
+    }
+}
+
+/// Rotates `start` around its local `axis` through its origin, following the picking ray from `start_ray` to `ray`.
+///
+/// Returns `start` unchanged if either ray misses the rotation plane or hits its center.
+pub fn drag_rotate_axis(start: &Rbt, axis: Axis, start_ray: &Ray, ray: &Ray) -> Rbt {
+    let dir = axis.of(start);
+    let (p0, p1) = match (
+        ray_plane(start.translation, dir, start_ray),
+        ray_plane(start.translation, dir, ray),
+    ) {
+        (Some(p0), Some(p1)) => (p0 - start.translation, p1 - start.translation),
+        _ => return start.clone(),
+    };
+    if p0.length_squared() <= f32::EPSILON || p1.length_squared() <= f32::EPSILON {
+        return start.clone();
+    }
+    let angle = math::atan2(p0.cross(p1).dot(dir), p0.dot(p1));
+    Rbt::from_t_r(
+        start.translation,
+        (Quat::from_axis_angle(dir, angle) * start.rotation).normalize(),
+    )
+}
@@ -0,0 +1,144 @@
+//! Bulk propagation of transforms through hierarchies.
+//!
+//! Hierarchies are flat arrays sorted so every parent comes before its children, as in `Skeleton`.
+//! A single forward scan then computes all global transforms.
+//! With the `rayon` feature, the `_par` variants spread the work over threads and give identical results.
+
+use crate::Rbt;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Writes the global transform of every node to `out`, `out[i] = out[parent[i]] * locals[i]`.
+///
+/// # Panics
+///
+/// Panics if the lengths differ or a parent doesn't come before its child.
+pub fn propagate(parents: &[Option<usize>], locals: &[Rbt], out: &mut [Rbt]) {
+    propagate_under(None, parents, locals, out);
+}
+
+/// Like `propagate`, for several hierarchies stored back to back, each under its own base transform.
+///
+/// Hierarchy `k` has `lengths[k]` nodes and roots at `bases[k]`. Its parent indices are relative to its first node.
+///
+/// # Panics
+///
+/// Panics if the lengths don't add up, or a parent doesn't come before its child.
+pub fn propagate_segmented(
+    lengths: &[usize],
+    bases: &[Rbt],
+    parents: &[Option<usize>],
+    locals: &[Rbt],
+    out: &mut [Rbt],
+) {
+    assert_eq!(lengths.len(), bases.len(), "length mismatch");
+    let mut start = 0;
+    for (&len, base) in lengths.iter().zip(bases) {
+        let end = start + len;
+        propagate_under(
+            Some(base),
+            &parents[start..end],
+            &locals[start..end],
+            &mut out[start..end],
+        );
+        start = end;
+    }
+    assert_eq!(start, parents.len(), "length mismatch");
+}
+
+/// Like `propagate`, with roots under `base` if any.
+pub(crate) fn propagate_under(
+    base: Option<&Rbt>,
+    parents: &[Option<usize>],
+    locals: &[Rbt],
+    out: &mut [Rbt],
+) {
+    assert!(
+        parents.len() == locals.len() && parents.len() == out.len(),
+        "length mismatch"
+    );
+    for i in 0..parents.len() {
+        out[i] = match parents[i] {
+            Some(p) => {
+                assert!(p < i, "parent after child");
+                &out[p] * &locals[i]
+            }
+            None => match base {
+                Some(base) => base * &locals[i],
+                None => locals[i].clone(),
+            },
+        };
+    }
+}
+
+/// Like `propagate`, processing the nodes of each depth in parallel.
+///
+/// Worth it for wide hierarchies, e.g. a scene with many root level objects.
+///
+/// # Panics
+///
+/// Panics if the lengths differ or a parent doesn't come before its child.
+#[cfg(feature = "rayon")]
+pub fn propagate_par(parents: &[Option<usize>], locals: &[Rbt], out: &mut [Rbt]) {
+    assert!(
+        parents.len() == locals.len() && parents.len() == out.len(),
+        "length mismatch"
+    );
+    let mut depths = vec![0usize; parents.len()];
+    let mut levels: Vec<Vec<usize>> = Vec::new();
+    for (i, parent) in parents.iter().enumerate() {
+        if let Some(p) = *parent {
+            assert!(p < i, "parent after child");
+            depths[i] = depths[p] + 1;
+        }
+        if levels.len() <= depths[i] {
+            levels.push(Vec::new());
+        }
+        levels[depths[i]].push(i);
+    }
+    for level in &levels {
+        let globals: Vec<Rbt> = level
+            .par_iter()
+            .map(|&i| match parents[i] {
+                Some(p) => &out[p] * &locals[i],
+                None => locals[i].clone(),
+            })
+            .collect();
+        for (&i, global) in level.iter().zip(globals) {
+            out[i] = global;
+        }
+    }
+}
+
+/// Like `propagate_segmented`, processing the hierarchies in parallel.
+///
+/// # Panics
+///
+/// Panics if the lengths don't add up, or a parent doesn't come before its child.
+#[cfg(feature = "rayon")]
+pub fn propagate_segmented_par(
+    lengths: &[usize],
+    bases: &[Rbt],
+    parents: &[Option<usize>],
+    locals: &[Rbt],
+    out: &mut [Rbt],
+) {
+    assert_eq!(lengths.len(), bases.len(), "length mismatch");
+    assert_eq!(
+        lengths.iter().sum::<usize>(),
+        parents.len(),
+        "length mismatch"
+    );
+    let mut segments = Vec::with_capacity(lengths.len());
+    let (mut parents, mut locals, mut out) = (parents, locals, out);
+    for (&len, base) in lengths.iter().zip(bases) {
+        let (p, rest_p) = parents.split_at(len);
+        let (l, rest_l) = locals.split_at(len);
+        let (o, rest_o) = out.split_at_mut(len);
+        segments.push((base, p, l, o));
+        (parents, locals, out) = (rest_p, rest_l, rest_o);
+    }
+    segments
+        .into_par_iter()
+        .for_each(|(base, p, l, o)| propagate_under(Some(base), p, l, o));
+}
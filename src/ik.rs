@@ -0,0 +1,217 @@
+//! Damped least-squares inverse kinematics.
+
+use crate::kinematics::{JacobianFrame, KinematicChain};
+use crate::lie::so3_log;
+use crate::{linalg, Rbt, Twist};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Settings of an iterative damped least-squares inverse kinematics solver.
+pub struct DlsIk {
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+    /// The damping factor λ. The update is `Jᵀ(JJᵀ + λ²I)⁻¹e`.
+    pub damping: f32,
+    /// The maximum absolute change of any joint value per iteration. The whole step is scaled down to respect it.
+    pub max_step: f32,
+    /// Stops when the end effector position error falls below this.
+    pub position_tolerance: f32,
+    /// Stops when the end effector orientation error, in radians, falls below this.
+    pub orientation_tolerance: f32,
+    /// Joint values the solver is pulled towards in the nullspace of the task, if any.
+    pub posture: Option<Vec<f32>>,
+    /// The gain of the pull towards `posture`, per iteration.
+    pub posture_gain: f32,
+}
+
+impl Default for DlsIk {
+    fn default() -> Self {
+        DlsIk {
+            max_iterations: 100,
+            damping: 0.05,
+            max_step: 0.2,
+            position_tolerance: 1e-4,
+            orientation_tolerance: 1e-4,
+            posture: None,
+            posture_gain: 0.1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of an inverse kinematics solve.
+pub struct IkSolution {
+    /// The joint values.
+    pub q: Vec<f32>,
+    /// The distance between the end effector and the goal position.
+    pub position_error: f32,
+    /// The angle between the end effector and the goal orientation, in radians.
+    pub orientation_error: f32,
+    /// The number of iterations run.
+    pub iterations: usize,
+    /// Whether both tolerances were reached.
+    pub converged: bool,
+}
+
+impl DlsIk {
+    /// Moves the end effector of `chain` towards `goal`, starting from joint values `initial`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial` or `posture` length is not `chain.dof()`.
+    pub fn solve(&self, chain: &KinematicChain, goal: &Rbt, initial: &[f32]) -> IkSolution {
+        let n = chain.dof();
+        let last = chain.links.len().saturating_sub(1);
+        if let Some(posture) = &self.posture {
+            assert_eq!(posture.len(), n, "wrong number of posture values");
+        }
+        let mut q = initial.to_vec();
+        let mut iterations = 0;
+        loop {
+            let current = chain.end_effector(&q);
+            let linear = goal.translation - current.translation;
+            let angular = so3_log(goal.rotation * current.rotation.conjugate());
+            let converged = linear.length() <= self.position_tolerance
+                && angular.length() <= self.orientation_tolerance;
+            if converged || iterations == self.max_iterations || chain.links.is_empty() {
+                return IkSolution {
+                    q,
+                    position_error: linear.length(),
+                    orientation_error: angular.length(),
+                    iterations,
+                    converged,
+                };
+            }
+            iterations += 1;
+
+            let j: Vec<[f64; 6]> = chain
+                .jacobian(&q, last, JacobianFrame::Base)
+                .iter()
+                .map(|c| c.to_array().map(|x| x as f64))
+                .collect();
+            let z: Vec<f64> = match &self.posture {
+                Some(posture) => posture
+                    .iter()
+                    .zip(&q)
+                    .map(|(p, q)| (self.posture_gain * (p - q)) as f64)
+                    .collect(),
+                None => vec![0.0; n],
+            };
+            let e = Twist::new(linear, angular).to_array().map(|x| x as f64);
+            let mut jz = [0.0; 6];
+            for (r, x) in jz.iter_mut().enumerate() {
+                *x = j.iter().zip(&z).map(|(c, z)| c[r] * z).sum();
+            }
+            let mut jjt = [[0.0; 6]; 6];
+            for (r, row) in jjt.iter_mut().enumerate() {
+                for (c, x) in row.iter_mut().enumerate() {
+                    *x = j.iter().map(|col| col[r] * col[c]).sum();
+                }
+            }
+            let regularized = |lambda2: f64| {
+                let mut a = jjt;
+                for (r, row) in a.iter_mut().enumerate() {
+                    row[r] += lambda2;
+                }
+                a
+            };
+            let damped = regularized((self.damping as f64).powi(2));
+            let Some(task) = linalg::solve_spd(&damped, &e) else {
+                return IkSolution {
+                    q,
+                    position_error: linear.length(),
+                    orientation_error: angular.length(),
+                    iterations,
+                    converged: false,
+                };
+            };
+            // The posture bias is projected with a nearly undamped pseudo-inverse so it doesn't leak into the task.
+            let null = linalg::solve_spd(&regularized(1e-9), &jz)
+                .or_else(|| linalg::solve_spd(&damped, &jz))
+                .unwrap_or([0.0; 6]);
+            // dq = Jᵀ(JJᵀ + λ²I)⁻¹e + (I - J⁺J)z
+            let mut dq: Vec<f32> = j
+                .iter()
+                .zip(&z)
+                .map(|(col, z)| {
+                    let y: f64 = (0..6).map(|r| col[r] * (task[r] - null[r])).sum();
+                    (z + y) as f32
+                })
+                .collect();
+            let largest = dq.iter().fold(0.0f32, |m, x| m.max(x.abs()));
+            if largest > self.max_step {
+                let s = self.max_step / largest;
+                dq.iter_mut().for_each(|x| *x *= s);
+            }
+            q.iter_mut().zip(&dq).for_each(|(q, dq)| *q += dq);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kinematics::Joint;
+    use crate::Vec3;
+
+    /// A 10 degree of freedom arm: spherical shoulder, elbow and wrist, and a revolute tool joint.
+    fn arm() -> KinematicChain {
+        let mut chain = KinematicChain::new();
+        let _ = chain
+            .push(Rbt::new(), Joint::Spherical)
+            .push(Rbt::from_t(Vec3::new(0.0, 0.0, 0.4)), Joint::Spherical)
+            .push(Rbt::from_t(Vec3::new(0.0, 0.0, 0.4)), Joint::Spherical)
+            .push(
+                Rbt::from_t(Vec3::new(0.0, 0.0, 0.1)),
+                Joint::Revolute(Vec3::X),
+            );
+        chain
+    }
+
+    #[test]
+    fn converges_on_reachable_goal() {
+        let chain = arm();
+        let goal = chain.end_effector(&[0.3, -0.2, 0.1, 0.2, 0.8, -0.1, 0.2, -0.3, 0.4, 0.5]);
+        let solution = DlsIk::default().solve(
+            &chain,
+            &goal,
+            &[0.1, 0.0, 0.0, 0.0, 0.3, 0.0, 0.0, 0.0, 0.0, 0.0],
+        );
+        assert!(solution.converged, "{solution:?}");
+        let reached = chain.end_effector(&solution.q);
+        assert!(reached.translation.distance(goal.translation) <= 1e-4);
+        assert!(so3_log(goal.rotation * reached.rotation.conjugate()).length() <= 1e-4);
+    }
+
+    #[test]
+    fn posture_bias_keeps_end_effector_in_place() {
+        let chain = arm();
+        let initial = [0.3, -0.2, 0.1, 0.2, 0.8, -0.1, 0.2, -0.3, 0.4, 0.5];
+        let goal = chain.end_effector(&initial);
+        let posture = vec![0.0; 10];
+        let ik = DlsIk {
+            max_iterations: 50,
+            // Never stop early, so every iteration applies the bias.
+            position_tolerance: -1.0,
+            orientation_tolerance: -1.0,
+            posture: Some(posture.clone()),
+            ..Default::default()
+        };
+        let solution = ik.solve(&chain, &goal, &initial);
+        assert_eq!(solution.iterations, 50);
+        assert!(solution.position_error < 1e-3, "{solution:?}");
+        assert!(solution.orientation_error < 1e-3, "{solution:?}");
+        let distance = |q: &[f32]| -> f32 {
+            q.iter()
+                .zip(&posture)
+                .map(|(q, p)| (q - p).powi(2))
+                .sum::<f32>()
+                .sqrt()
+        };
+        assert!(
+            distance(&solution.q) < 0.8 * distance(&initial),
+            "{} vs {}",
+            distance(&solution.q),
+            distance(&initial)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! IMU preintegration on manifold.
+//!
+//! Follows Forster et al., "On-Manifold Preintegration for Real-Time Visual-Inertial Odometry".
+//! Measurements are in the body frame and exclude gravity compensation, i.e. an accelerometer at rest reads `-gravity`.
+
+use crate::lie::{skew, so3_exp, so3_left_jacobian_mul};
+use crate::{Mat3, Quat, Rbt, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Gyroscope and accelerometer samples accumulated between two keyframes `i` and `j`.
+///
+/// The deltas are independent of the state at `i`, so they needn't be recomputed when it changes.
+pub struct Preintegration {
+    /// The gyroscope bias the samples are corrected with.
+    pub gyro_bias: Vec3,
+    /// The accelerometer bias the samples are corrected with.
+    pub accel_bias: Vec3,
+    /// The rotation from `j` to `i`.
+    pub delta_rotation: Quat,
+    /// The velocity change, in frame `i`, without gravity.
+    pub delta_velocity: Vec3,
+    /// The position change, in frame `i`, without gravity and initial velocity.
+    pub delta_position: Vec3,
+    /// The integrated time.
+    pub delta_time: f32,
+    /// The Jacobian of the right perturbation of `delta_rotation` with respect to `gyro_bias`.
+    pub d_rotation_d_gyro_bias: Mat3,
+    /// The Jacobian of `delta_velocity` with respect to `gyro_bias`.
+    pub d_velocity_d_gyro_bias: Mat3,
+    /// The Jacobian of `delta_velocity` with respect to `accel_bias`.
+    pub d_velocity_d_accel_bias: Mat3,
+    /// The Jacobian of `delta_position` with respect to `gyro_bias`.
+    pub d_position_d_gyro_bias: Mat3,
+    /// The Jacobian of `delta_position` with respect to `accel_bias`.
+    pub d_position_d_accel_bias: Mat3,
+}
+
+impl Default for Preintegration {
+    fn default() -> Self {
+        Preintegration::new(Vec3::ZERO, Vec3::ZERO)
+    }
+}
+
+impl Preintegration {
+    /// Starts an empty preintegration with given biases.
+    pub fn new(gyro_bias: Vec3, accel_bias: Vec3) -> Self {
+        Preintegration {
+            gyro_bias,
+            accel_bias,
+            delta_rotation: Quat::IDENTITY,
+            delta_velocity: Vec3::ZERO,
+            delta_position: Vec3::ZERO,
+            delta_time: 0.0,
+            d_rotation_d_gyro_bias: Mat3::ZERO,
+            d_velocity_d_gyro_bias: Mat3::ZERO,
+            d_velocity_d_accel_bias: Mat3::ZERO,
+            d_position_d_gyro_bias: Mat3::ZERO,
+            d_position_d_accel_bias: Mat3::ZERO,
+        }
+    }
+
+    /// Accumulates angular velocity `gyro` and specific force `accel`, held constant for `dt`.
+    pub fn integrate(&mut self, gyro: Vec3, accel: Vec3, dt: f32) {
+        let omega = (gyro - self.gyro_bias) * dt;
+        let a = accel - self.accel_bias;
+        let r = Mat3::from_quat(self.delta_rotation);
+        let ra_skew = r * skew(a);
+        let dt2 = dt * dt;
+
+        self.delta_position += self.delta_velocity * dt + r * a * (0.5 * dt2);
+        self.delta_velocity += r * a * dt;
+
+        self.d_position_d_accel_bias += self.d_velocity_d_accel_bias * dt - r * (0.5 * dt2);
+        self.d_position_d_gyro_bias +=
+            self.d_velocity_d_gyro_bias * dt - ra_skew * self.d_rotation_d_gyro_bias * (0.5 * dt2);
+        self.d_velocity_d_accel_bias -= r * dt;
+        self.d_velocity_d_gyro_bias -= ra_skew * self.d_rotation_d_gyro_bias * dt;
+
+        let step = so3_exp(omega);
+        // The right Jacobian is the left Jacobian at the negated rotation vector.
+        let right_jacobian = Mat3::from_cols(
+            so3_left_jacobian_mul(-omega, Vec3::X),
+            so3_left_jacobian_mul(-omega, Vec3::Y),
+            so3_left_jacobian_mul(-omega, Vec3::Z),
+        );
+        self.d_rotation_d_gyro_bias =
+            Mat3::from_quat(step).transpose() * self.d_rotation_d_gyro_bias - right_jacobian * dt;
+        self.delta_rotation = (self.delta_rotation * step).normalize();
+        self.delta_time += dt;
+    }
+
+    /// Returns the relative pose from `j` to `i`, ignoring gravity and initial velocity.
+    pub fn delta_rbt(&self) -> Rbt {
+        Rbt::from_t_r(self.delta_position, self.delta_rotation)
+    }
+
+    /// Returns the deltas re-linearized to new biases to first order, as `(delta_rbt, delta_velocity)`.
+    ///
+    /// Cheap compared to integrating again, and accurate for small bias changes.
+    pub fn corrected(&self, gyro_bias: Vec3, accel_bias: Vec3) -> (Rbt, Vec3) {
+        let dg = gyro_bias - self.gyro_bias;
+        let da = accel_bias - self.accel_bias;
+        let rotation = self.delta_rotation * so3_exp(self.d_rotation_d_gyro_bias * dg);
+        let velocity = self.delta_velocity
+            + self.d_velocity_d_gyro_bias * dg
+            + self.d_velocity_d_accel_bias * da;
+        let position = self.delta_position
+            + self.d_position_d_gyro_bias * dg
+            + self.d_position_d_accel_bias * da;
+        (Rbt::from_t_r(position, rotation.normalize()), velocity)
+    }
+
+    /// Predicts the pose and velocity at `j` from the pose and velocity at `i`, both in the world frame.
+    pub fn predict(&self, pose: &Rbt, velocity: Vec3, gravity: Vec3) -> (Rbt, Vec3) {
+        let t = self.delta_time;
+        let translation = pose.translation
+            + velocity * t
+            + gravity * (0.5 * t * t)
+            + pose.rotation * self.delta_position;
+        let rotation = (pose.rotation * self.delta_rotation).normalize();
+        let velocity = velocity + gravity * t + pose.rotation * self.delta_velocity;
+        (Rbt::from_t_r(translation, rotation), velocity)
+    }
+}
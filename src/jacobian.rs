@@ -0,0 +1,173 @@
+//! Analytic Jacobians of SE(3) operations.
+//!
+//! Jacobians are taken with respect to a twist perturbation `δ` (see [`Twist`]) of the input pose,
+//! applied on the left, `exp(δ) * x`, or on the right, `x * exp(δ)`.
+//! The output pose is perturbed with the same convention.
+
+use crate::lie::{mat3x6_from_blocks, skew, Mat3x6, Mat6};
+use crate::{Mat3, Rbt, Twist, Vec3};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The side a tangent perturbation is applied on.
+pub enum Perturbation {
+    /// `exp(δ) * x`, a perturbation in the parent frame.
+    Left,
+    /// `x * exp(δ)`, a perturbation in the local frame of `x`.
+    Right,
+}
+
+/// Returns the 3x6 Jacobian of `rbt * point` with respect to a perturbation of `rbt`.
+pub fn point_jacobian(rbt: &Rbt, point: Vec3, perturbation: Perturbation) -> Mat3x6 {
+    match perturbation {
+        Perturbation::Left => {
+            let q = rbt.rotation * point + rbt.translation;
+            mat3x6_from_blocks(Mat3::IDENTITY, -skew(q))
+        }
+        Perturbation::Right => {
+            let r = Mat3::from_quat(rbt.rotation);
+            mat3x6_from_blocks(r, -(r * skew(point)))
+        }
+    }
+}
+
+/// Returns the 6x6 Jacobians of `a * b` with respect to perturbations of `a` and `b`.
+pub fn compose_jacobians(a: &Rbt, b: &Rbt, perturbation: Perturbation) -> (Mat6, Mat6) {
+    match perturbation {
+        Perturbation::Left => (identity(), a.adjoint()),
+        Perturbation::Right => (b.inverse().adjoint(), identity()),
+    }
+}
+
+/// Returns the 6x6 Jacobian of `rbt.inverse()` with respect to a perturbation of `rbt`.
+pub fn inverse_jacobian(rbt: &Rbt, perturbation: Perturbation) -> Mat6 {
+    let adjoint = match perturbation {
+        Perturbation::Left => rbt.inverse().adjoint(),
+        Perturbation::Right => rbt.adjoint(),
+    };
+    adjoint.map(|row| row.map(|x| -x))
+}
+
+/// Returns the 6x6 identity matrix.
+pub fn identity() -> Mat6 {
+    let mut m = [[0.0; 6]; 6];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    m
+}
+
+/// Applies a perturbation to `rbt`.
+pub fn perturb(rbt: &Rbt, delta: &Twist, perturbation: Perturbation) -> Rbt {
+    match perturbation {
+        Perturbation::Left => Rbt::exp(delta) * rbt,
+        Perturbation::Right => rbt * Rbt::exp(delta),
+    }
+}
+
+/// Returns the `M`x6 Jacobian of `f` at `rbt` by central differences with step `eps`.
+///
+/// Useful to validate analytic Jacobians.
+pub fn numeric_jacobian<const M: usize>(
+    rbt: &Rbt,
+    perturbation: Perturbation,
+    eps: f32,
+    f: impl Fn(&Rbt) -> [f32; M],
+) -> [[f32; 6]; M] {
+    let mut j = [[0.0; 6]; M];
+    for k in 0..6 {
+        let mut delta = [0.0; 6];
+        delta[k] = eps;
+        let delta = Twist::from_array(delta);
+        let plus = f(&perturb(rbt, &delta, perturbation));
+        let minus = f(&perturb(rbt, &-delta, perturbation));
+        for (row, (p, m)) in j.iter_mut().zip(plus.iter().zip(&minus)) {
+            row[k] = (p - m) / (2.0 * eps);
+        }
+    }
+    j
+}
+
+/// Returns the 6x6 Jacobian of pose valued `f` at `rbt` by central differences with step `eps`.
+///
+/// The output is perturbed with the same convention as the input.
+pub fn numeric_pose_jacobian(
+    rbt: &Rbt,
+    perturbation: Perturbation,
+    eps: f32,
+    f: impl Fn(&Rbt) -> Rbt,
+) -> Mat6 {
+    let y = f(rbt);
+    let y_inv = y.inverse();
+    numeric_jacobian(rbt, perturbation, eps, |x| {
+        let y1 = f(x);
+        match perturbation {
+            Perturbation::Left => (&y1 * &y_inv).log(),
+            Perturbation::Right => (&y_inv * &y1).log(),
+        }
+        .to_array()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Quat;
+
+    const PERTURBATIONS: [Perturbation; 2] = [Perturbation::Left, Perturbation::Right];
+
+    fn max_error<const M: usize>(a: &[[f32; 6]; M], b: &[[f32; 6]; M]) -> f32 {
+        a.iter()
+            .flatten()
+            .zip(b.iter().flatten())
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f32::max)
+    }
+
+    fn poses() -> (Rbt, Rbt) {
+        (
+            Rbt::from_t_r(
+                Vec3::new(0.5, -1.2, 2.0),
+                Quat::from_axis_angle(Vec3::new(1.0, 2.0, -0.5).normalize(), 0.9),
+            ),
+            Rbt::from_t_r(
+                Vec3::new(-0.7, 0.3, 1.1),
+                Quat::from_axis_angle(Vec3::new(-0.3, 0.4, 1.0).normalize(), 1.7),
+            ),
+        )
+    }
+
+    #[test]
+    fn point_jacobian_matches_numeric() {
+        let (rbt, _) = poses();
+        let point = Vec3::new(0.4, 1.5, -0.8);
+        for perturbation in PERTURBATIONS {
+            let numeric = numeric_jacobian(&rbt, perturbation, 1e-2, |x| {
+                (x.rotation * point + x.translation).to_array()
+            });
+            let error = max_error(&point_jacobian(&rbt, point, perturbation), &numeric);
+            assert!(error < 1e-3, "error {error} for {perturbation:?}");
+        }
+    }
+
+    #[test]
+    fn compose_jacobians_match_numeric() {
+        let (a, b) = poses();
+        for perturbation in PERTURBATIONS {
+            let (ja, jb) = compose_jacobians(&a, &b, perturbation);
+            let numeric_a = numeric_pose_jacobian(&a, perturbation, 1e-2, |x| x * &b);
+            let numeric_b = numeric_pose_jacobian(&b, perturbation, 1e-2, |x| &a * x);
+            let error = max_error(&ja, &numeric_a).max(max_error(&jb, &numeric_b));
+            assert!(error < 1e-3, "error {error} for {perturbation:?}");
+        }
+    }
+
+    #[test]
+    fn inverse_jacobian_matches_numeric() {
+        let (rbt, _) = poses();
+        for perturbation in PERTURBATIONS {
+            let numeric = numeric_pose_jacobian(&rbt, perturbation, 1e-2, Rbt::inverse);
+            let error = max_error(&inverse_jacobian(&rbt, perturbation), &numeric);
+            assert!(error < 1e-3, "error {error} for {perturbation:?}");
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Streaming newline-delimited JSON pose records.
+//!
+//! Each line is one JSON object such as
+//! `{"time":1.5,"frame":"map","child_frame":"base","translation":[1,2,3],"rotation":[0,0,0,1]}`,
+//! with rotation as `[x, y, z, w]`. Unknown fields are kept in [`PoseRecord::extra`].
+//! A zero rotation quaternion is a parse error.
+
+use crate::{Quat, Rbt, Vec3};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+/// A timestamped pose between two named frames.
+pub struct PoseRecord {
+    /// The time in seconds.
+    pub time: f64,
+    /// The frame the pose is expressed in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame: Option<String>,
+    /// The frame whose pose this is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub child_frame: Option<String>,
+    /// The translation.
+    pub translation: [f32; 3],
+    /// The rotation as `[x, y, z, w]`.
+    #[serde(deserialize_with = "nonzero_rotation")]
+    pub rotation: [f32; 4],
+    /// Any other fields.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PoseRecord {
+    /// Creates a record without frame names.
+    pub fn new(time: f64, rbt: &Rbt) -> Self {
+        PoseRecord {
+            time,
+            translation: rbt.translation.to_array(),
+            rotation: rbt.rotation.to_array(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the pose, with the rotation normalized.
+    ///
+    /// The rotation is NaN if `rotation` is zero, which reading never produces.
+    pub fn rbt(&self) -> Rbt {
+        Rbt::from_t_r(
+            Vec3::from_array(self.translation),
+            Quat::from_array(self.rotation).normalize(),
+        )
+    }
+}
+
+fn nonzero_rotation<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[f32; 4], D::Error> {
+    let rotation = <[f32; 4]>::deserialize(deserializer)?;
+    if Quat::from_array(rotation).length_squared() == 0.0 {
+        return Err(D::Error::custom("rotation quaternion is zero"));
+    }
+    Ok(rotation)
+}
+
+#[derive(Debug)]
+/// An error reading a record.
+pub enum JsonlError {
+    /// Reading failed.
+    Io(io::Error),
+    /// A line isn't a valid record.
+    Parse {
+        /// The 1-based line number.
+        line: usize,
+        /// The JSON error.
+        error: serde_json::Error,
+    },
+}
+
+impl fmt::Display for JsonlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonlError::Io(e) => write!(f, "{}", e),
+            JsonlError::Parse { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for JsonlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JsonlError::Io(e) => Some(e),
+            JsonlError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Reads records line by line.
+pub struct JsonlReader<R> {
+    reader: R,
+    line: usize,
+    buf: String,
+}
+
+impl<R: BufRead> JsonlReader<R> {
+    /// Creates a reader.
+    pub fn new(reader: R) -> Self {
+        JsonlReader {
+            reader,
+            line: 0,
+            buf: String::new(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for JsonlReader<R> {
+    type Item = Result<PoseRecord, JsonlError>;
+
+    /// Returns the next record, skipping blank lines.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(e) => return Some(Err(JsonlError::Io(e))),
+            }
+            let trimmed = self.buf.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            return Some(
+                serde_json::from_str(trimmed).map_err(|error| JsonlError::Parse {
+                    line: self.line,
+                    error,
+                }),
+            );
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Writes records line by line.
+///
+/// Opening a file in append mode extends an existing log.
+pub struct JsonlWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> JsonlWriter<W> {
+    /// Creates a writer.
+    pub fn new(writer: W) -> Self {
+        JsonlWriter { writer }
+    }
+
+    /// Writes one record as a line.
+    pub fn write(&mut self, record: &PoseRecord) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(i: usize) -> PoseRecord {
+        let i = i as f32;
+        let rbt = Rbt::from_t_r(Vec3::new(i, -2.0 * i, 0.5), Quat::from_rotation_y(0.3 * i));
+        PoseRecord {
+            frame: Some("map".to_string()),
+            child_frame: (i > 0.0).then(|| "base".to_string()),
+            ..PoseRecord::new(0.5 * i as f64, &rbt)
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let records: Vec<PoseRecord> = (0..4).map(record).collect();
+        let mut writer = JsonlWriter::new(Vec::new());
+        for r in &records {
+            writer.write(r).unwrap();
+        }
+        let buf = writer.into_inner();
+        let read: Vec<PoseRecord> = JsonlReader::new(buf.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, records);
+    }
+
+    #[test]
+    fn keeps_unknown_fields() {
+        let line = r#"{"time":1.5,"translation":[1,2,3],"rotation":[0,0,0,1],"sensor":"lidar","quality":0.9}"#;
+        let text = format!("\n{line}\n\n");
+        let mut reader = JsonlReader::new(text.as_bytes());
+        let record = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        assert_eq!(record.rbt(), Rbt::from_t(Vec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(record.extra["sensor"], "lidar");
+        assert_eq!(record.extra["quality"], 0.9);
+
+        let mut writer = JsonlWriter::new(Vec::new());
+        writer.write(&record).unwrap();
+        let written: serde_json::Value = serde_json::from_slice(&writer.into_inner()).unwrap();
+        assert_eq!(written["sensor"], "lidar");
+        assert_eq!(written["quality"], 0.9);
+    }
+
+    #[test]
+    fn rejects_zero_quaternion() {
+        let text = "{\"time\":0,\"translation\":[0,0,0],\"rotation\":[0,0,0,1]}\n{\"time\":1,\"translation\":[0,0,0],\"rotation\":[0,0,0,0]}\n";
+        let mut reader = JsonlReader::new(text.as_bytes());
+        assert!(reader.next().unwrap().is_ok());
+        match reader.next().unwrap() {
+            Err(JsonlError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("{other:?}"),
+        }
+    }
+}
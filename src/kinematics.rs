@@ -0,0 +1,222 @@
+//! Serial kinematic chains.
+
+use crate::lie::{so3_exp, so3_left_jacobian_mul};
+use crate::{Quat, Rbt, Twist, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A joint between two links.
+pub enum Joint {
+    /// No motion.
+    Fixed,
+    /// Rotation around a unit axis, by an angle in radians.
+    Revolute(Vec3),
+    /// Translation along a unit axis.
+    Prismatic(Vec3),
+    /// Free rotation, given as a rotation vector of 3 values.
+    Spherical,
+}
+
+impl Joint {
+    /// Returns the number of joint values this joint consumes.
+    pub fn dof(&self) -> usize {
+        match self {
+            Joint::Fixed => 0,
+            Joint::Revolute(_) | Joint::Prismatic(_) => 1,
+            Joint::Spherical => 3,
+        }
+    }
+
+    /// Returns the joint motion for joint values `q`, which must have length `dof()`.
+    pub fn transform(&self, q: &[f32]) -> Rbt {
+        match self {
+            Joint::Fixed => Rbt::new(),
+            Joint::Revolute(axis) => Rbt::from_r(Quat::from_axis_angle(*axis, q[0])),
+            Joint::Prismatic(axis) => Rbt::from_t(*axis * q[0]),
+            Joint::Spherical => Rbt::from_r(so3_exp(Vec3::new(q[0], q[1], q[2]))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The frame a Jacobian is expressed in.
+pub enum JacobianFrame {
+    /// Axes of the base frame.
+    Base,
+    /// Axes of the link the Jacobian is computed at.
+    Link,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A link of a kinematic chain.
+///
+/// The link frame is the parent link frame, moved by `offset` and then by the joint motion.
+pub struct Link {
+    /// The joint frame relative to the parent link frame.
+    pub offset: Rbt,
+    /// The joint.
+    pub joint: Joint,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A serial chain of links, starting from the base frame.
+pub struct KinematicChain {
+    /// The links, from base to end effector.
+    pub links: Vec<Link>,
+}
+
+impl KinematicChain {
+    /// Creates an empty chain.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends a link.
+    pub fn push(&mut self, offset: Rbt, joint: Joint) -> &mut Self {
+        self.links.push(Link { offset, joint });
+        self
+    }
+
+    /// Returns the total number of joint values.
+    pub fn dof(&self) -> usize {
+        self.links.iter().map(|link| link.joint.dof()).sum()
+    }
+
+    /// Returns the frames of all links in the base frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q.len()` is not `dof()`.
+    pub fn forward_kinematics(&self, q: &[f32]) -> Vec<Rbt> {
+        assert_eq!(q.len(), self.dof(), "wrong number of joint values");
+        let mut frames = Vec::with_capacity(self.links.len());
+        let mut frame = Rbt::new();
+        let mut i = 0;
+        for link in &self.links {
+            let dof = link.joint.dof();
+            frame = frame * &link.offset * link.joint.transform(&q[i..i + dof]);
+            frames.push(frame.clone());
+            i += dof;
+        }
+        frames
+    }
+
+    /// Returns the geometric Jacobian at the origin of link `link`, one column per joint value.
+    ///
+    /// Each column is the twist of the link origin per unit joint velocity: linear velocity of the origin and angular velocity,
+    /// in the axes of `frame`. Columns of joints after `link` are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q.len()` is not `dof()` or `link` is out of bounds.
+    pub fn jacobian(&self, q: &[f32], link: usize, frame: JacobianFrame) -> Vec<Twist> {
+        let frames = self.forward_kinematics(q);
+        let target = &frames[link];
+        let mut columns = Vec::with_capacity(q.len());
+        let mut parent = Rbt::new();
+        let mut i = 0;
+        for (j, l) in self.links.iter().enumerate() {
+            let dof = l.joint.dof();
+            if j <= link {
+                let joint = &parent * &l.offset;
+                let r = target.translation - joint.translation;
+                match &l.joint {
+                    Joint::Fixed => {}
+                    Joint::Revolute(axis) => {
+                        let w = joint.rotation * *axis;
+                        columns.push(Twist::new(w.cross(r), w));
+                    }
+                    Joint::Prismatic(axis) => {
+                        columns.push(Twist::new(joint.rotation * *axis, Vec3::ZERO))
+                    }
+                    Joint::Spherical => {
+                        let phi = Vec3::new(q[i], q[i + 1], q[i + 2]);
+                        for e in [Vec3::X, Vec3::Y, Vec3::Z] {
+                            let w = joint.rotation * so3_left_jacobian_mul(phi, e);
+                            columns.push(Twist::new(w.cross(r), w));
+                        }
+                    }
+                }
+            } else {
+                columns.extend(std::iter::repeat_n(Twist::ZERO, dof));
+            }
+            parent = frames[j].clone();
+            i += dof;
+        }
+        if frame == JacobianFrame::Link {
+            let inv = target.rotation.conjugate();
+            for c in &mut columns {
+                *c = Twist::new(inv * c.linear, inv * c.angular);
+            }
+        }
+        columns
+    }
+
+    /// Returns the frame of the last link in the base frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q.len()` is not `dof()`.
+    pub fn end_effector(&self, q: &[f32]) -> Rbt {
+        self.forward_kinematics(q).pop().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lie::so3_log;
+
+    fn chain() -> KinematicChain {
+        let mut chain = KinematicChain::new();
+        let _ = chain
+            .push(
+                Rbt::from_t(Vec3::new(0.0, 0.0, 0.3)),
+                Joint::Revolute(Vec3::Z),
+            )
+            .push(
+                Rbt::from_t_r(Vec3::new(0.1, 0.0, 0.4), Quat::from_rotation_x(0.3)),
+                Joint::Prismatic(Vec3::new(0.6, 0.0, 0.8)),
+            )
+            .push(Rbt::from_t(Vec3::new(0.0, 0.5, 0.0)), Joint::Fixed)
+            .push(Rbt::from_t(Vec3::new(0.2, 0.0, 0.1)), Joint::Spherical)
+            .push(
+                Rbt::from_t(Vec3::new(0.0, 0.0, 0.25)),
+                Joint::Revolute(Vec3::Y),
+            );
+        chain
+    }
+
+    #[test]
+    fn jacobian_matches_finite_differences() {
+        let chain = chain();
+        let q = [0.4, 0.2, 0.3, -0.5, 0.7, -0.6];
+        let eps = 1e-3;
+        for link in 0..chain.links.len() {
+            for frame in [JacobianFrame::Base, JacobianFrame::Link] {
+                let target = &chain.forward_kinematics(&q)[link];
+                let jacobian = chain.jacobian(&q, link, frame);
+                assert_eq!(jacobian.len(), q.len());
+                for (k, column) in jacobian.iter().enumerate() {
+                    let moved = |d: f32| {
+                        let mut q = q;
+                        q[k] += d;
+                        chain.forward_kinematics(&q)[link].clone()
+                    };
+                    let (plus, minus) = (moved(eps), moved(-eps));
+                    let mut linear = (plus.translation - minus.translation) / (2.0 * eps);
+                    let mut angular =
+                        so3_log(plus.rotation * minus.rotation.conjugate()) / (2.0 * eps);
+                    if frame == JacobianFrame::Link {
+                        linear = target.rotation.conjugate() * linear;
+                        angular = target.rotation.conjugate() * angular;
+                    }
+                    let error = (column.linear - linear)
+                        .abs()
+                        .max_element()
+                        .max((column.angular - angular).abs().max_element());
+                    assert!(error < 1e-3, "link {link}, column {k}, {frame:?}: {error}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Multiple rigid body transforms processed in SIMD lanes.
+
+use crate::{Quat, Rbt, Vec3};
+use std::ops::Mul;
+use wide::{f32x4, f32x8};
+
+macro_rules! lanes {
+    ($n:literal, $f:ident, $vec3:ident, $quat:ident, $rbt:ident) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Default)]
+        #[doc = concat!($n, " `Vec3`s, one per lane.")]
+        pub struct $vec3 {
+            /// The x components.
+            pub x: $f,
+            /// The y components.
+            pub y: $f,
+            /// The z components.
+            pub z: $f,
+        }
+
+        impl $vec3 {
+            /// Puts `v` in every lane.
+            pub fn splat(v: Vec3) -> Self {
+                $vec3 {
+                    x: $f::splat(v.x),
+                    y: $f::splat(v.y),
+                    z: $f::splat(v.z),
+                }
+            }
+
+            /// Puts one vector in each lane.
+            pub fn from_array(vs: [Vec3; $n]) -> Self {
+                $vec3 {
+                    x: vs.map(|v| v.x).into(),
+                    y: vs.map(|v| v.y).into(),
+                    z: vs.map(|v| v.z).into(),
+                }
+            }
+
+            /// Returns the vector in each lane.
+            pub fn to_array(&self) -> [Vec3; $n] {
+                let (x, y, z) = (self.x.to_array(), self.y.to_array(), self.z.to_array());
+                std::array::from_fn(|i| Vec3::new(x[i], y[i], z[i]))
+            }
+
+            fn cross(&self, rhs: &Self) -> Self {
+                $vec3 {
+                    x: self.y * rhs.z - self.z * rhs.y,
+                    y: self.z * rhs.x - self.x * rhs.z,
+                    z: self.x * rhs.y - self.y * rhs.x,
+                }
+            }
+
+            fn add(&self, rhs: &Self) -> Self {
+                $vec3 {
+                    x: self.x + rhs.x,
+                    y: self.y + rhs.y,
+                    z: self.z + rhs.z,
+                }
+            }
+
+            fn scale(&self, s: $f) -> Self {
+                $vec3 {
+                    x: self.x * s,
+                    y: self.y * s,
+                    z: self.z * s,
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[doc = concat!($n, " `Quat`s, one per lane.")]
+        pub struct $quat {
+            /// The x components.
+            pub x: $f,
+            /// The y components.
+            pub y: $f,
+            /// The z components.
+            pub z: $f,
+            /// The w components.
+            pub w: $f,
+        }
+
+        impl Default for $quat {
+            fn default() -> Self {
+                Self::splat(Quat::IDENTITY)
+            }
+        }
+
+        impl $quat {
+            /// Puts `q` in every lane.
+            pub fn splat(q: Quat) -> Self {
+                $quat {
+                    x: $f::splat(q.x),
+                    y: $f::splat(q.y),
+                    z: $f::splat(q.z),
+                    w: $f::splat(q.w),
+                }
+            }
+
+            /// Puts one quaternion in each lane.
+            pub fn from_array(qs: [Quat; $n]) -> Self {
+                $quat {
+                    x: qs.map(|q| q.x).into(),
+                    y: qs.map(|q| q.y).into(),
+                    z: qs.map(|q| q.z).into(),
+                    w: qs.map(|q| q.w).into(),
+                }
+            }
+
+            /// Returns the quaternion in each lane.
+            pub fn to_array(&self) -> [Quat; $n] {
+                let (x, y, z, w) = (
+                    self.x.to_array(),
+                    self.y.to_array(),
+                    self.z.to_array(),
+                    self.w.to_array(),
+                );
+                std::array::from_fn(|i| Quat::from_xyzw(x[i], y[i], z[i], w[i]))
+            }
+
+            fn xyz(&self) -> $vec3 {
+                $vec3 {
+                    x: self.x,
+                    y: self.y,
+                    z: self.z,
+                }
+            }
+
+            fn mul_quat(&self, rhs: &Self) -> Self {
+                $quat {
+                    x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+                    y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+                    z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+                    w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+                }
+            }
+
+            fn normalize(&self) -> Self {
+                let inv_len = $f::splat(1.0)
+                    / (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
+                        .sqrt();
+                $quat {
+                    x: self.x * inv_len,
+                    y: self.y * inv_len,
+                    z: self.z * inv_len,
+                    w: self.w * inv_len,
+                }
+            }
+
+            fn conjugate(&self) -> Self {
+                $quat {
+                    x: -self.x,
+                    y: -self.y,
+                    z: -self.z,
+                    w: self.w,
+                }
+            }
+
+            fn rotate(&self, v: &$vec3) -> $vec3 {
+                let u = self.xyz();
+                let t = u.cross(v).scale($f::splat(2.0));
+                v.add(&t.scale(self.w)).add(&u.cross(&t))
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Default)]
+        #[doc = concat!($n, " `Rbt`s, one per lane.")]
+        pub struct $rbt {
+            /// The translation parts.
+            pub translation: $vec3,
+            /// The rotation parts.
+            pub rotation: $quat,
+        }
+
+        impl $rbt {
+            /// Puts `rbt` in every lane.
+            pub fn splat(rbt: &Rbt) -> Self {
+                $rbt {
+                    translation: $vec3::splat(rbt.translation),
+                    rotation: $quat::splat(rbt.rotation),
+                }
+            }
+
+            /// Puts one transform in each lane.
+            pub fn from_array(rbts: &[Rbt; $n]) -> Self {
+                $rbt {
+                    translation: $vec3::from_array(std::array::from_fn(|i| rbts[i].translation)),
+                    rotation: $quat::from_array(std::array::from_fn(|i| rbts[i].rotation)),
+                }
+            }
+
+            /// Returns the transform in each lane.
+            pub fn to_array(&self) -> [Rbt; $n] {
+                let t = self.translation.to_array();
+                let r = self.rotation.to_array();
+                std::array::from_fn(|i| Rbt::from_t_r(t[i], r[i]))
+            }
+
+            /// Returns the inverse of each lane.
+            pub fn inverse(&self) -> Self {
+                let inv_r = self.rotation.conjugate();
+                let t = inv_r.rotate(&self.translation);
+                $rbt {
+                    translation: $vec3 {
+                        x: -t.x,
+                        y: -t.y,
+                        z: -t.z,
+                    },
+                    rotation: inv_r,
+                }
+            }
+
+            /// Transforms the point in each lane by the transform in the same lane.
+            pub fn transform_points(&self, points: &$vec3) -> $vec3 {
+                self.rotation.rotate(points).add(&self.translation)
+            }
+
+            /// Rotates the vector in each lane by the transform in the same lane.
+            pub fn transform_vectors(&self, vectors: &$vec3) -> $vec3 {
+                self.rotation.rotate(vectors)
+            }
+        }
+
+        impl Mul<&$rbt> for &$rbt {
+            type Output = $rbt;
+
+            fn mul(self, rhs: &$rbt) -> $rbt {
+                $rbt {
+                    translation: self
+                        .rotation
+                        .rotate(&rhs.translation)
+                        .add(&self.translation),
+                    rotation: self.rotation.mul_quat(&rhs.rotation).normalize(),
+                }
+            }
+        }
+
+        impl Mul<$rbt> for $rbt {
+            type Output = $rbt;
+
+            fn mul(self, rhs: $rbt) -> $rbt {
+                &self * &rhs
+            }
+        }
+
+        impl Mul<$rbt> for &$rbt {
+            type Output = $rbt;
+
+            fn mul(self, rhs: $rbt) -> $rbt {
+                self * &rhs
+            }
+        }
+
+        impl Mul<&$rbt> for $rbt {
+            type Output = $rbt;
+
+            fn mul(self, rhs: &$rbt) -> $rbt {
+                &self * rhs
+            }
+        }
+    };
+}
+
+lanes!(4, f32x4, Vec3x4, Quatx4, Rbtx4);
+lanes!(8, f32x8, Vec3x8, Quatx8, Rbtx8);
@@ -1,5 +1,25 @@
 //! Rigid body transform.
+//!
+//! # Features
+//!
+//! - `csv`: Enables the `csv` module.
+//! - `deterministic`: Routes transcendental and normalization math through `libm` and disables glam's SIMD paths,
+//!   so results are bit-identical across platforms. Doesn't apply to the `lanes` module.
+//! - `f16`: Enables `PackedRbtF16`.
+//! - `flatbuffers`: Enables the `flatbuffers` module.
+//! - `jsonl`: Enables the `jsonl` module.
+//! - `libm`: Routes scalar math through `libm`. Required when `std` is disabled.
+//! - `postcard`: Enables the `binary` module.
+//! - `protobuf`: Enables the `protobuf` module.
+//! - `rayon`: Adds parallel propagation to the `hierarchy` module.
+//! - `serde`: Implements `Serialize` and `Deserialize` for `Rbt` and `StampedRbt`.
+//! - `std` (default): Enables the modules that allocate or use the standard library. Without it the crate is
+//!   `no_std` and keeps `Rbt`, `Rbt2`, the geometry types, `PoseRingBuffer`, and `serde` and `postcard` support.
+//! - `ultraviolet`: Enables the `uv` module and conversions between `Rbt` and `ultraviolet::Isometry3`.
+//! - `uom`: Adds constructors and accessors taking translations as `uom` lengths.
+//! - `wide`: Enables the `lanes` module.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     explicit_outlives_requirements,
     keyword_idents,
@@ -9,7 +29,6 @@
     missing_docs,
     missing_debug_implementations,
     noop_method_call,
-    pointer_structural_match,
     trivial_casts,
     trivial_numeric_casts,
     unsafe_code,
@@ -21,8 +40,142 @@
     variant_size_differences
 )]
 
-pub use glam::{Mat3, Mat4, Quat, Vec3, Vec4};
-use std::ops::Mul;
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+mod aabb;
+mod affine;
+#[cfg(feature = "std")]
+pub mod align;
+mod aligned;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "postcard")]
+pub mod binary;
+#[cfg(feature = "std")]
+pub mod camera;
+mod compose;
+#[cfg(feature = "std")]
+pub mod constraints;
+#[cfg(feature = "std")]
+pub mod conventions;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "std")]
+mod double_buffer;
+#[cfg(feature = "std")]
+pub mod dynamics;
+mod fixed;
+#[cfg(feature = "std")]
+mod fixed_step;
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffers;
+mod framed;
+#[cfg(feature = "std")]
+pub mod gauss_newton;
+#[cfg(feature = "std")]
+pub mod geodetic;
+#[cfg(feature = "std")]
+pub mod gizmo;
+#[cfg(feature = "std")]
+pub mod hierarchy;
+#[cfg(feature = "std")]
+pub mod ik;
+#[cfg(feature = "std")]
+pub mod imu;
+#[cfg(feature = "std")]
+pub mod jacobian;
+#[cfg(feature = "jsonl")]
+pub mod jsonl;
+#[cfg(feature = "std")]
+pub mod kinematics;
+#[cfg(feature = "wide")]
+pub mod lanes;
+#[cfg(feature = "std")]
+pub mod lie;
+#[cfg(feature = "std")]
+mod linalg;
+mod line;
+mod math;
+mod matrix;
+#[cfg(feature = "std")]
+pub mod motion_model;
+mod obb;
+#[cfg(feature = "std")]
+pub mod odometry;
+#[cfg(feature = "f16")]
+mod packed;
+#[cfg(feature = "std")]
+pub mod path;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+mod ray;
+mod rbt2;
+mod ring;
+#[cfg(feature = "std")]
+mod rng;
+#[cfg(feature = "std")]
+pub mod scene;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod sim3;
+#[cfg(feature = "std")]
+pub mod simulation;
+#[cfg(feature = "std")]
+pub mod skeleton;
+mod sphere;
+mod stamped;
+#[cfg(feature = "std")]
+pub mod stereo;
+#[cfg(feature = "std")]
+pub mod store;
+#[cfg(feature = "std")]
+mod swept;
+#[cfg(feature = "std")]
+pub mod time_scaling;
+#[cfg(feature = "std")]
+pub mod track;
+#[cfg(feature = "std")]
+pub mod trajectory;
+#[cfg(feature = "std")]
+pub mod uncertainty;
+#[cfg(feature = "uom")]
+mod units;
+#[cfg(feature = "ultraviolet")]
+pub mod uv;
+mod view;
+#[cfg(feature = "std")]
+mod vision;
+#[cfg(feature = "std")]
+mod world;
+
+pub use aabb::Aabb;
+pub use aligned::RbtA;
+pub use compose::NormalizePolicy;
+use core::ops::{Div, Mul};
+#[cfg(feature = "std")]
+pub use double_buffer::{PoseSnapshot, TransformDoubleBuffer};
+pub use fixed::FixedRbt;
+#[cfg(feature = "std")]
+pub use fixed_step::FixedStepInterpolator;
+pub use framed::FramedRbt;
+pub use glam::{Affine3A, DVec3, EulerRot, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+#[cfg(feature = "std")]
+pub use lie::{Manifold, Twist};
+pub use line::{PluckerLine, Segment};
+pub use matrix::RbtM;
+pub use obb::Obb;
+#[cfg(feature = "f16")]
+pub use packed::PackedRbtF16;
+pub use ray::Ray;
+pub use rbt2::{Plane, Rbt2};
+pub use ring::PoseRingBuffer;
+pub use sim3::Sim3;
+pub use sphere::Sphere;
+pub use stamped::StampedRbt;
+pub use view::{RbtMut, RbtRef};
+#[cfg(feature = "std")]
+pub use world::{FloatingOrigin, WorldRbt};
 
 #[derive(Debug, Clone, PartialEq, Default)]
 /// A rigid body transform.
@@ -80,6 +233,20 @@ impl Rbt {
         result
     }
 
+    /// Converts the inverse of `self` to a 4x4 matrix, without constructing the inverse `Rbt`.
+    ///
+    /// Useful for camera view matrices.
+    pub fn to_view_mat4(&self) -> Mat4 {
+        let inv_r = Mat3::from_quat(self.rotation).transpose();
+        let t = -(inv_r * self.translation);
+        Mat4::from_cols(
+            inv_r.x_axis.extend(0.0),
+            inv_r.y_axis.extend(0.0),
+            inv_r.z_axis.extend(0.0),
+            t.extend(1.0),
+        )
+    }
+
     /// Returns the inverse of `self`.
     pub fn inverse(&self) -> Self {
         let r = self.rotation.as_ref();
@@ -90,6 +257,68 @@ impl Rbt {
         }
     }
 
+    /// Transforms a plane `n·x = d`, given as `(n, d)`.
+    pub fn transform_plane(&self, plane: Vec4) -> Vec4 {
+        let n = self.rotation * plane.truncate();
+        n.extend(plane.w + n.dot(self.translation))
+    }
+
+    /// Transforms a plane `n·x = d`, given as `(n, d)`, by the inverse of `self`.
+    pub fn inverse_transform_plane(&self, plane: Vec4) -> Vec4 {
+        let n = plane.truncate();
+        (self.rotation.conjugate() * n).extend(plane.w - n.dot(self.translation))
+    }
+
+    /// Transforms `point` by the inverse of `self`, without constructing the inverse.
+    ///
+    /// Equivalent to `self.inverse() * point.extend(1.0)`, with half the work.
+    pub fn transform_point_inverse(&self, point: Vec3) -> Vec3 {
+        self.rotation.conjugate() * (point - self.translation)
+    }
+
+    /// Rotates `vector` by the inverse rotation of `self`, without constructing the inverse.
+    pub fn transform_vector_inverse(&self, vector: Vec3) -> Vec3 {
+        self.rotation.conjugate() * vector
+    }
+
+    /// Mirrors `self` across the plane through `plane_point` with unit normal `plane_normal`.
+    ///
+    /// With `M` the reflection, the rotation becomes `M R M`, which is proper. Local axes perpendicular to
+    /// `plane_normal` map to the mirror images of the original axes, and the local axis along `plane_normal`
+    /// to the negated mirror image. E.g. mirroring across the YZ plane mirrors the local Y and Z axes and keeps
+    /// the local X axis pointing the same way along world X.
+    pub fn mirrored(&self, plane_point: Vec3, plane_normal: Vec3) -> Rbt {
+        let n = plane_normal;
+        let r = self.rotation;
+        let v = Vec3::new(r.x, r.y, r.z);
+        let v = n * (2.0 * v.dot(n)) - v;
+        Rbt {
+            translation: self.translation - n * (2.0 * (self.translation - plane_point).dot(n)),
+            rotation: Quat::from_xyzw(v.x, v.y, v.z, r.w),
+        }
+    }
+
+    /// Interpolates translation linearly and rotation spherically along the shortest path.
+    ///
+    /// Returns `self` at `t = 0` and `other` at `t = 1`.
+    pub fn lerp(&self, other: &Rbt, t: f32) -> Rbt {
+        Rbt {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+        }
+    }
+
+    /// Returns `self` expressed in the frame of `other`, `other⁻¹ * self`, without constructing the inverse.
+    ///
+    /// The complement of `self / other`, which is `self * other⁻¹`.
+    pub fn relative_to(&self, other: &Rbt) -> Rbt {
+        let inv_r = other.rotation.conjugate();
+        Rbt {
+            translation: inv_r * (self.translation - other.translation),
+            rotation: (inv_r * self.rotation).normalize(),
+        }
+    }
+
     /// Perform transform `m` to coordinate system `o` with repect to coordinate system `a`.
     pub fn do_m_to_o_wrt_a(m: &Rbt, o: &Rbt, a: &Rbt) -> Rbt {
         a * m * a.inverse() * o
@@ -146,3 +375,42 @@ impl Mul<Vec4> for Rbt {
         &self * rhs
     }
 }
+
+/// `a / b` is `a * b⁻¹`: `a` relative to `b` measured in the parent frame, so `(a / b) * b == a`.
+///
+/// For `a` expressed in the frame of `b`, `b⁻¹ * a`, see `Rbt::relative_to`.
+impl Div<&Rbt> for &Rbt {
+    type Output = Rbt;
+
+    fn div(self, rhs: &Rbt) -> Rbt {
+        let rotation = (self.rotation * rhs.rotation.conjugate()).normalize();
+        Rbt {
+            translation: self.translation - rotation * rhs.translation,
+            rotation,
+        }
+    }
+}
+
+impl Div<Rbt> for &Rbt {
+    type Output = Rbt;
+
+    fn div(self, rhs: Rbt) -> Rbt {
+        self / &rhs
+    }
+}
+
+impl Div<&Rbt> for Rbt {
+    type Output = Rbt;
+
+    fn div(self, rhs: &Rbt) -> Rbt {
+        &self / rhs
+    }
+}
+
+impl Div<Rbt> for Rbt {
+    type Output = Rbt;
+
+    fn div(self, rhs: Rbt) -> Rbt {
+        &self / &rhs
+    }
+}
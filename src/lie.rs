@@ -0,0 +1,419 @@
+//! Lie group operations of SE(3).
+//!
+//! Tangent vectors are [`Twist`]s. Perturbations are applied on the right, `x * exp(δ)`, unless stated otherwise.
+
+use crate::{math, Mat3, Quat, Rbt, Rbt2, Vec3};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A 6x6 matrix, row major, acting on `Twist::to_array` ordering.
+pub type Mat6 = [[f32; 6]; 6];
+
+/// A 3x6 matrix, row major.
+pub type Mat3x6 = [[f32; 6]; 3];
+
+/// Returns the skew-symmetric matrix `[v]×` such that `[v]× * u == v.cross(u)`.
+pub fn skew(v: Vec3) -> Mat3 {
+    Mat3::from_cols(
+        Vec3::new(0.0, v.z, -v.y),
+        Vec3::new(-v.z, 0.0, v.x),
+        Vec3::new(v.y, -v.x, 0.0),
+    )
+}
+
+/// Builds a 6x6 matrix from 3x3 blocks.
+pub(crate) fn mat6_from_blocks(a: Mat3, b: Mat3, c: Mat3, d: Mat3) -> Mat6 {
+    let mut m = [[0.0; 6]; 6];
+    for i in 0..3 {
+        for j in 0..3 {
+            m[i][j] = a.col(j)[i];
+            m[i][j + 3] = b.col(j)[i];
+            m[i + 3][j] = c.col(j)[i];
+            m[i + 3][j + 3] = d.col(j)[i];
+        }
+    }
+    m
+}
+
+/// Builds a 3x6 matrix from 3x3 blocks.
+pub(crate) fn mat3x6_from_blocks(a: Mat3, b: Mat3) -> Mat3x6 {
+    let mut m = [[0.0; 6]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for j in 0..3 {
+            row[j] = a.col(j)[i];
+            row[j + 3] = b.col(j)[i];
+        }
+    }
+    m
+}
+
+/// Returns `m * v`.
+pub fn mat6_mul_twist(m: &Mat6, v: &Twist) -> Twist {
+    let v = v.to_array();
+    Twist::from_array(m.map(|row| row.iter().zip(&v).map(|(a, b)| a * b).sum()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// A tangent vector of SE(3), e.g. a velocity or a small pose difference.
+///
+/// As an array, the linear part comes first.
+pub struct Twist {
+    /// The linear part.
+    pub linear: Vec3,
+    /// The angular part, as a rotation vector.
+    pub angular: Vec3,
+}
+
+impl Twist {
+    /// The zero twist.
+    pub const ZERO: Twist = Twist {
+        linear: Vec3::ZERO,
+        angular: Vec3::ZERO,
+    };
+
+    /// Creates a twist from linear and angular parts.
+    pub fn new(linear: Vec3, angular: Vec3) -> Self {
+        Twist { linear, angular }
+    }
+
+    /// Creates a twist from `[linear, angular]`.
+    pub fn from_array(a: [f32; 6]) -> Self {
+        Twist {
+            linear: Vec3::new(a[0], a[1], a[2]),
+            angular: Vec3::new(a[3], a[4], a[5]),
+        }
+    }
+
+    /// Returns `[linear, angular]`.
+    pub fn to_array(&self) -> [f32; 6] {
+        let (l, a) = (self.linear, self.angular);
+        [l.x, l.y, l.z, a.x, a.y, a.z]
+    }
+
+    /// Returns the Euclidean norm.
+    pub fn length(&self) -> f32 {
+        math::sqrt(self.linear.length_squared() + self.angular.length_squared())
+    }
+}
+
+impl Add for Twist {
+    type Output = Twist;
+
+    fn add(self, rhs: Twist) -> Twist {
+        Twist::new(self.linear + rhs.linear, self.angular + rhs.angular)
+    }
+}
+
+impl Sub for Twist {
+    type Output = Twist;
+
+    fn sub(self, rhs: Twist) -> Twist {
+        Twist::new(self.linear - rhs.linear, self.angular - rhs.angular)
+    }
+}
+
+impl Neg for Twist {
+    type Output = Twist;
+
+    fn neg(self) -> Twist {
+        Twist::new(-self.linear, -self.angular)
+    }
+}
+
+impl Mul<f32> for Twist {
+    type Output = Twist;
+
+    fn mul(self, rhs: f32) -> Twist {
+        Twist::new(self.linear * rhs, self.angular * rhs)
+    }
+}
+
+/// Returns the rotation of rotation vector `phi`.
+pub fn so3_exp(phi: Vec3) -> Quat {
+    let theta2 = phi.length_squared();
+    let theta = math::sqrt(theta2);
+    let (s, c) = if theta < 1e-4 {
+        (0.5 - theta2 / 48.0, 1.0 - theta2 / 8.0)
+    } else {
+        (math::sin(theta * 0.5) / theta, math::cos(theta * 0.5))
+    };
+    Quat::from_xyzw(phi.x * s, phi.y * s, phi.z * s, c).normalize()
+}
+
+/// Returns the rotation vector of `q`, with angle in `[0, π]`.
+pub fn so3_log(q: Quat) -> Vec3 {
+    let q = if q.w < 0.0 { -q } else { q };
+    let v = Vec3::new(q.x, q.y, q.z);
+    let s = v.length();
+    if s < 1e-4 {
+        // θ / s ≈ 2 / w for small angles.
+        v * (2.0 / q.w)
+    } else {
+        v * (2.0 * math::atan2(s, q.w) / s)
+    }
+}
+
+/// Returns the coefficients `((1 - cos θ) / θ², (θ - sin θ) / θ³)` of the SO(3) left Jacobian.
+fn jacobian_coefficients(theta2: f32) -> (f32, f32) {
+    if theta2 < 1e-6 {
+        (0.5 - theta2 / 24.0, 1.0 / 6.0 - theta2 / 120.0)
+    } else {
+        let theta = math::sqrt(theta2);
+        (
+            (1.0 - math::cos(theta)) / theta2,
+            (theta - math::sin(theta)) / (theta2 * theta),
+        )
+    }
+}
+
+/// Multiplies `v` by the left Jacobian of SO(3) at `phi`.
+pub(crate) fn so3_left_jacobian_mul(phi: Vec3, v: Vec3) -> Vec3 {
+    let (a, b) = jacobian_coefficients(phi.length_squared());
+    let pv = phi.cross(v);
+    v + pv * a + phi.cross(pv) * b
+}
+
+/// Multiplies `v` by the inverse of the left Jacobian of SO(3) at `phi`.
+pub(crate) fn so3_left_jacobian_inv_mul(phi: Vec3, v: Vec3) -> Vec3 {
+    let theta2 = phi.length_squared();
+    let c = if theta2 < 1e-6 {
+        1.0 / 12.0 + theta2 / 720.0
+    } else {
+        let theta = math::sqrt(theta2);
+        1.0 / theta2 - (1.0 + math::cos(theta)) / (2.0 * theta * math::sin(theta))
+    };
+    let pv = phi.cross(v);
+    v - pv * 0.5 + phi.cross(pv) * c
+}
+
+/// Returns the left Jacobian of SO(3) at `phi` as a matrix.
+fn so3_left_jacobian(phi: Vec3) -> Mat3 {
+    Mat3::from_cols(
+        so3_left_jacobian_mul(phi, Vec3::X),
+        so3_left_jacobian_mul(phi, Vec3::Y),
+        so3_left_jacobian_mul(phi, Vec3::Z),
+    )
+}
+
+/// Returns the left Jacobian of SE(3) at `twist`.
+///
+/// `exp(ξ + δ) ≈ exp(J δ) * exp(ξ)` for small `δ`.
+pub fn se3_left_jacobian(twist: &Twist) -> Mat6 {
+    let (rho, phi) = (skew(twist.linear), skew(twist.angular));
+    let theta2 = twist.angular.length_squared();
+    let (a, b, c) = if theta2 < 1e-2 {
+        (
+            1.0 / 6.0 - theta2 / 120.0,
+            1.0 / 24.0 - theta2 / 720.0,
+            1.0 / 120.0 - theta2 / 2520.0,
+        )
+    } else {
+        // The numerators cancel badly in `f32`.
+        let t2 = theta2 as f64;
+        let t = t2.sqrt();
+        let (s, co) = (math::sin_f64(t), math::cos_f64(t));
+        let a = (t - s) / (t2 * t);
+        let b = (t2 * 0.5 + co - 1.0) / (t2 * t2);
+        let c = 0.5 * (b + 3.0 * (t - s - t2 * t / 6.0) / (t2 * t2 * t));
+        (a as f32, b as f32, c as f32)
+    };
+    let pr = phi * rho;
+    let rp = rho * phi;
+    let prp = pr * phi;
+    let q = rho * 0.5
+        + (pr + rp + prp) * a
+        + (phi * pr + rp * phi - prp * 3.0) * b
+        + (prp * phi + phi * prp) * c;
+    let j = so3_left_jacobian(twist.angular);
+    mat6_from_blocks(j, q, Mat3::ZERO, j)
+}
+
+/// Returns the right Jacobian of SE(3) at `twist`.
+///
+/// `exp(ξ + δ) ≈ exp(ξ) * exp(J δ)` for small `δ`.
+pub fn se3_right_jacobian(twist: &Twist) -> Mat6 {
+    se3_left_jacobian(&-*twist)
+}
+
+impl Rbt {
+    /// Returns the transform of `twist` integrated over unit time, the exponential map of SE(3).
+    pub fn exp(twist: &Twist) -> Rbt {
+        Rbt {
+            translation: so3_left_jacobian_mul(twist.angular, twist.linear),
+            rotation: so3_exp(twist.angular),
+        }
+    }
+
+    /// Returns the adjoint matrix, which maps twists in the local frame of `self` to the parent frame.
+    ///
+    /// `self * exp(δ) == exp(adjoint * δ) * self`.
+    pub fn adjoint(&self) -> Mat6 {
+        let r = Mat3::from_quat(self.rotation);
+        mat6_from_blocks(r, skew(self.translation) * r, Mat3::ZERO, r)
+    }
+
+    /// Returns the twist that `exp` maps to `self`, the logarithm map of SE(3).
+    ///
+    /// The rotation angle is in `[0, π]`.
+    pub fn log(&self) -> Twist {
+        let phi = so3_log(self.rotation);
+        Twist {
+            linear: so3_left_jacobian_inv_mul(phi, self.translation),
+            angular: phi,
+        }
+    }
+
+    /// Interpolates along the screw motion from `self` to `other`, with constant body twist.
+    ///
+    /// Returns `self` at `t = 0` and `other` at `t = 1`.
+    pub fn screw_interpolate(&self, other: &Rbt, t: f32) -> Rbt {
+        self * Rbt::exp(&((self.inverse() * other).log() * t))
+    }
+
+    /// Returns `n` poses evenly spaced along the screw motion from `self` to `to`, excluding both ends.
+    pub fn sweep(&self, to: &Rbt, n: usize) -> Vec<Rbt> {
+        let twist = (self.inverse() * to).log();
+        (1..=n)
+            .map(|i| self * Rbt::exp(&(twist * (i as f32 / (n + 1) as f32))))
+            .collect()
+    }
+
+    /// Returns the fewest evenly spaced poses along the screw motion from `self` to `to`, excluding both ends,
+    /// such that consecutive poses are at most `max_translation` apart and `max_rotation` radians apart.
+    ///
+    /// The translation step is measured along the path of the origin, so it bounds the chord too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_translation` or `max_rotation` is not positive.
+    pub fn sweep_adaptive(&self, to: &Rbt, max_translation: f32, max_rotation: f32) -> Vec<Rbt> {
+        assert!(max_translation > 0.0, "max_translation must be positive");
+        assert!(max_rotation > 0.0, "max_rotation must be positive");
+        let twist = (self.inverse() * to).log();
+        let segments = (twist.linear.length() / max_translation)
+            .max(twist.angular.length() / max_rotation)
+            .ceil()
+            .max(1.0) as usize;
+        self.sweep(to, segments - 1)
+    }
+}
+
+/// A manifold with local parameterization, for plugging into optimizers.
+///
+/// `boxminus` is the inverse of `boxplus`: `x.boxplus(&y.boxminus(x))` is `y`.
+pub trait Manifold: Sized {
+    /// The tangent space type.
+    type Tangent;
+
+    /// The dimension of the tangent space.
+    const DIM: usize;
+
+    /// Moves `self` by `delta` in its tangent space.
+    fn boxplus(&self, delta: &Self::Tangent) -> Self;
+
+    /// Returns the tangent that moves `other` to `self`.
+    fn boxminus(&self, other: &Self) -> Self::Tangent;
+
+    /// Maps `delta` back to the manifold around `self`.
+    ///
+    /// The exponential map, so the same as `boxplus` unless overridden.
+    fn retract(&self, delta: &Self::Tangent) -> Self {
+        self.boxplus(delta)
+    }
+}
+
+impl Manifold for Rbt {
+    type Tangent = Twist;
+    const DIM: usize = 6;
+
+    /// Returns `self * exp(delta)`.
+    fn boxplus(&self, delta: &Twist) -> Rbt {
+        self * Rbt::exp(delta)
+    }
+
+    /// Returns `log(other⁻¹ * self)`.
+    fn boxminus(&self, other: &Rbt) -> Twist {
+        (other.inverse() * self).log()
+    }
+}
+
+impl Manifold for Quat {
+    type Tangent = Vec3;
+    const DIM: usize = 3;
+
+    /// Returns `self * exp(delta)`.
+    fn boxplus(&self, delta: &Vec3) -> Quat {
+        (*self * so3_exp(*delta)).normalize()
+    }
+
+    /// Returns `log(other⁻¹ * self)`.
+    fn boxminus(&self, other: &Quat) -> Vec3 {
+        so3_log(other.conjugate() * *self)
+    }
+}
+
+impl Manifold for Rbt2 {
+    /// `(vx, vy, ω)`.
+    type Tangent = Vec3;
+    const DIM: usize = 3;
+
+    /// Returns `self * exp(delta)`.
+    fn boxplus(&self, delta: &Vec3) -> Rbt2 {
+        self * Rbt2::exp(*delta)
+    }
+
+    /// Returns `log(other⁻¹ * self)`.
+    fn boxminus(&self, other: &Rbt2) -> Vec3 {
+        (other.inverse() * self).log()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the largest difference between the columns of `j` and central differences of `f` along each axis.
+    fn max_error(j: &Mat6, f: impl Fn(&Twist) -> Twist) -> f32 {
+        let eps = 1e-2;
+        let mut error = 0.0f32;
+        for k in 0..6 {
+            let mut delta = [0.0; 6];
+            delta[k] = eps;
+            let delta = Twist::from_array(delta);
+            let column = (f(&delta) - f(&-delta)) * (0.5 / eps);
+            for (r, x) in column.to_array().iter().enumerate() {
+                error = error.max((x - j[r][k]).abs());
+            }
+        }
+        error
+    }
+
+    fn twists() -> [Twist; 3] {
+        [
+            Twist::new(Vec3::new(0.7, -1.1, 0.4), Vec3::new(1.2, 0.8, -1.5)),
+            Twist::new(Vec3::new(-0.3, 0.2, 0.9), Vec3::new(0.01, -0.02, 0.03)),
+            Twist::new(Vec3::new(1.0, 2.0, -0.5), Vec3::new(0.0, 0.0, 2.5)),
+        ]
+    }
+
+    #[test]
+    fn se3_left_jacobian_matches_finite_differences() {
+        for xi in twists() {
+            let inv = Rbt::exp(&xi).inverse();
+            let error = max_error(&se3_left_jacobian(&xi), |d| {
+                (Rbt::exp(&(xi + *d)) * &inv).log()
+            });
+            assert!(error < 1e-3, "error {error} at {xi:?}");
+        }
+    }
+
+    #[test]
+    fn se3_right_jacobian_matches_finite_differences() {
+        for xi in twists() {
+            let inv = Rbt::exp(&xi).inverse();
+            let error = max_error(&se3_right_jacobian(&xi), |d| {
+                (&inv * Rbt::exp(&(xi + *d))).log()
+            });
+            assert!(error < 1e-3, "error {error} at {xi:?}");
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Lines and segments.
+
+use crate::{Rbt, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A line segment between two points.
+pub struct Segment {
+    /// The start point.
+    pub start: Vec3,
+    /// The end point.
+    pub end: Vec3,
+}
+
+impl Segment {
+    /// Creates a segment from two points.
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Segment { start, end }
+    }
+
+    /// Returns the point at parameter `t`, where 0 is `start` and 1 is `end`.
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.start.lerp(self.end, t)
+    }
+
+    /// Returns the infinite line through `self`.
+    pub fn line(&self) -> PluckerLine {
+        PluckerLine::from_points(self.start, self.end)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// An infinite line in Plücker coordinates.
+///
+/// For a line through point `p` with direction `d`, the moment is `p × d`.
+/// Coordinates are homogeneous: scaling both parts describes the same line.
+pub struct PluckerLine {
+    /// The direction.
+    pub direction: Vec3,
+    /// The moment about the origin.
+    pub moment: Vec3,
+}
+
+impl PluckerLine {
+    /// Creates a line through `point` along `direction`.
+    pub fn from_point_direction(point: Vec3, direction: Vec3) -> Self {
+        PluckerLine {
+            direction,
+            moment: point.cross(direction),
+        }
+    }
+
+    /// Creates a line through `a` and `b`, directed from `a` to `b`.
+    pub fn from_points(a: Vec3, b: Vec3) -> Self {
+        Self::from_point_direction(a, b - a)
+    }
+
+    /// Returns the point on the line closest to the origin.
+    pub fn closest_point_to_origin(&self) -> Vec3 {
+        self.direction.cross(self.moment) / self.direction.length_squared()
+    }
+
+    /// Returns the distance from `point` to the line.
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        (self.moment - point.cross(self.direction)).length() / self.direction.length()
+    }
+
+    /// Returns the reciprocal product of `self` and `other`.
+    ///
+    /// Zero if and only if the lines are coplanar.
+    /// Its sign tells the handedness of one line winding around the other.
+    pub fn reciprocal_product(&self, other: &PluckerLine) -> f32 {
+        self.direction.dot(other.moment) + other.direction.dot(self.moment)
+    }
+
+    /// Returns the distance between `self` and `other`.
+    pub fn distance(&self, other: &PluckerLine) -> f32 {
+        let cross = self.direction.cross(other.direction);
+        let len = cross.length();
+        if len <= f32::EPSILON * self.direction.length() * other.direction.length() {
+            // Parallel lines.
+            other.distance_to_point(self.closest_point_to_origin())
+        } else {
+            self.reciprocal_product(other).abs() / len
+        }
+    }
+}
+
+impl Mul<&Segment> for &Rbt {
+    type Output = Segment;
+
+    fn mul(self, rhs: &Segment) -> Segment {
+        Segment {
+            start: self.rotation * rhs.start + self.translation,
+            end: self.rotation * rhs.end + self.translation,
+        }
+    }
+}
+
+impl Mul<Segment> for &Rbt {
+    type Output = Segment;
+
+    fn mul(self, rhs: Segment) -> Segment {
+        self * &rhs
+    }
+}
+
+impl Mul<&Segment> for Rbt {
+    type Output = Segment;
+
+    fn mul(self, rhs: &Segment) -> Segment {
+        &self * rhs
+    }
+}
+
+impl Mul<Segment> for Rbt {
+    type Output = Segment;
+
+    fn mul(self, rhs: Segment) -> Segment {
+        &self * &rhs
+    }
+}
+
+impl Mul<&PluckerLine> for &Rbt {
+    type Output = PluckerLine;
+
+    fn mul(self, rhs: &PluckerLine) -> PluckerLine {
+        let direction = self.rotation * rhs.direction;
+        PluckerLine {
+            direction,
+            moment: self.rotation * rhs.moment + self.translation.cross(direction),
+        }
+    }
+}
+
+impl Mul<PluckerLine> for &Rbt {
+    type Output = PluckerLine;
+
+    fn mul(self, rhs: PluckerLine) -> PluckerLine {
+        self * &rhs
+    }
+}
+
+impl Mul<&PluckerLine> for Rbt {
+    type Output = PluckerLine;
+
+    fn mul(self, rhs: &PluckerLine) -> PluckerLine {
+        &self * rhs
+    }
+}
+
+impl Mul<PluckerLine> for Rbt {
+    type Output = PluckerLine;
+
+    fn mul(self, rhs: PluckerLine) -> PluckerLine {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,112 @@
+//! Scalar math routed through `libm` when the `libm` feature is enabled, e.g. by `deterministic`.
+//!
+//! Use these instead of the `f32` methods so results don't depend on the platform's math library.
+
+// Most callers live in modules that need `std`.
+#![cfg_attr(not(feature = "std"), allow(dead_code, unused_imports))]
+
+#[cfg(feature = "libm")]
+mod imp {
+    pub fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    pub fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    pub fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+
+    pub fn ln(x: f32) -> f32 {
+        libm::logf(x)
+    }
+
+    pub fn sin_f64(x: f64) -> f64 {
+        libm::sin(x)
+    }
+
+    pub fn cos_f64(x: f64) -> f64 {
+        libm::cos(x)
+    }
+
+    pub fn atan2_f64(y: f64, x: f64) -> f64 {
+        libm::atan2(y, x)
+    }
+
+    pub fn sqrt_f64(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    pub fn hypot_f64(x: f64, y: f64) -> f64 {
+        libm::hypot(x, y)
+    }
+
+    pub fn round_f64(x: f64) -> f64 {
+        libm::round(x)
+    }
+}
+
+#[cfg(not(feature = "libm"))]
+mod imp {
+    pub fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    pub fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    pub fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    pub fn ln(x: f32) -> f32 {
+        x.ln()
+    }
+
+    pub fn sin_f64(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub fn cos_f64(x: f64) -> f64 {
+        x.cos()
+    }
+
+    pub fn atan2_f64(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+
+    pub fn sqrt_f64(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub fn hypot_f64(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
+    pub fn round_f64(x: f64) -> f64 {
+        x.round()
+    }
+}
+
+pub(crate) use imp::{
+    atan2, atan2_f64, cos, cos_f64, hypot_f64, ln, round_f64, sin, sin_f64, sqrt, sqrt_f64,
+};
+
+/// Wraps `angle` into `[-π, π)`.
+pub(crate) fn wrap_angle(angle: f32) -> f32 {
+    use core::f32::consts::{PI, TAU};
+    // `rem_euclid` lives in `std`; this is its definition for a positive divisor.
+    let r = (angle + PI) % TAU;
+    (if r < 0.0 { r + TAU } else { r }) - PI
+}
@@ -0,0 +1,122 @@
+//! Rigid body transform with a rotation matrix.
+
+use crate::{Mat3, Quat, Rbt, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A rigid body transform storing the rotation as a matrix, for transforming many points per pose.
+///
+/// Composing doesn't re-orthonormalize, so convert back to `Rbt` for long chains.
+pub struct RbtM {
+    /// The translation part.
+    pub translation: Vec3,
+    /// The rotation part, orthonormal.
+    pub rotation: Mat3,
+}
+
+impl Default for RbtM {
+    fn default() -> Self {
+        RbtM {
+            translation: Vec3::ZERO,
+            rotation: Mat3::IDENTITY,
+        }
+    }
+}
+
+impl RbtM {
+    /// Returns the identity transform.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Converts from a `Rbt`.
+    pub fn from_rbt(rbt: &Rbt) -> Self {
+        RbtM {
+            translation: rbt.translation,
+            rotation: Mat3::from_quat(rbt.rotation),
+        }
+    }
+
+    /// Converts to a `Rbt`.
+    ///
+    /// Won't check for rigidness.
+    pub fn to_rbt(&self) -> Rbt {
+        Rbt::from_t_r(
+            self.translation,
+            Quat::from_mat3(&self.rotation).normalize(),
+        )
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let inv_r = self.rotation.transpose();
+        RbtM {
+            translation: -(inv_r * self.translation),
+            rotation: inv_r,
+        }
+    }
+
+    /// Transforms `point`.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rotation * point + self.translation
+    }
+
+    /// Transforms `vector`, ignoring translation.
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rotation * vector
+    }
+
+    /// Transforms `points` in place.
+    pub fn transform_points_in_place(&self, points: &mut [Vec3]) {
+        for p in points {
+            *p = self.rotation * *p + self.translation;
+        }
+    }
+}
+
+impl From<Rbt> for RbtM {
+    fn from(rbt: Rbt) -> Self {
+        RbtM::from_rbt(&rbt)
+    }
+}
+
+impl From<RbtM> for Rbt {
+    fn from(rbt: RbtM) -> Self {
+        rbt.to_rbt()
+    }
+}
+
+impl Mul<&RbtM> for &RbtM {
+    type Output = RbtM;
+
+    fn mul(self, rhs: &RbtM) -> RbtM {
+        RbtM {
+            translation: self.rotation * rhs.translation + self.translation,
+            rotation: self.rotation * rhs.rotation,
+        }
+    }
+}
+
+impl Mul<RbtM> for &RbtM {
+    type Output = RbtM;
+
+    fn mul(self, rhs: RbtM) -> RbtM {
+        self * &rhs
+    }
+}
+
+impl Mul<&RbtM> for RbtM {
+    type Output = RbtM;
+
+    fn mul(self, rhs: &RbtM) -> RbtM {
+        &self * rhs
+    }
+}
+
+impl Mul<RbtM> for RbtM {
+    type Output = RbtM;
+
+    fn mul(self, rhs: RbtM) -> RbtM {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,59 @@
+//! Motion models for tracking filters.
+//!
+//! Pose Jacobians are with respect to right perturbations, `x * exp(δ)`, of the input and predicted poses.
+
+use crate::lie::{se3_right_jacobian, Mat6};
+use crate::{Rbt, Twist, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A predicted pose with the Jacobians of its perturbation.
+pub struct Prediction {
+    /// The predicted pose.
+    pub rbt: Rbt,
+    /// The Jacobian with respect to the perturbation of the current pose.
+    pub pose_jacobian: Mat6,
+    /// The Jacobian with respect to a change of the body twist over the step, the process noise of a constant velocity model.
+    pub twist_jacobian: Mat6,
+}
+
+/// Extrapolates `rbt` moving with constant body twist `twist` for `dt`.
+///
+/// The prediction is `rbt * exp(twist * dt)`.
+pub fn constant_velocity(rbt: &Rbt, twist: &Twist, dt: f32) -> Prediction {
+    let step = *twist * dt;
+    let motion = Rbt::exp(&step);
+    let mut twist_jacobian = se3_right_jacobian(&step);
+    twist_jacobian
+        .iter_mut()
+        .for_each(|row| row.iter_mut().for_each(|x| *x *= dt));
+    Prediction {
+        rbt: rbt * &motion,
+        pose_jacobian: motion.inverse().adjoint(),
+        twist_jacobian,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A constant turn rate and velocity prediction with the Jacobians of its perturbation.
+pub struct CtrvPrediction {
+    /// The predicted pose.
+    pub rbt: Rbt,
+    /// The Jacobian with respect to the perturbation of the current pose.
+    pub pose_jacobian: Mat6,
+    /// The Jacobian with respect to `(speed, yaw_rate)`, one column each.
+    pub input_jacobian: [[f32; 2]; 6],
+}
+
+/// Extrapolates `rbt` moving forward along local X at `speed` while turning around local Z at `yaw_rate`, for `dt`.
+///
+/// The path is an exact arc in the local XY plane.
+pub fn constant_turn_rate(rbt: &Rbt, speed: f32, yaw_rate: f32, dt: f32) -> CtrvPrediction {
+    let twist = Twist::new(Vec3::new(speed, 0.0, 0.0), Vec3::new(0.0, 0.0, yaw_rate));
+    let prediction = constant_velocity(rbt, &twist, dt);
+    let j = &prediction.twist_jacobian;
+    CtrvPrediction {
+        rbt: prediction.rbt,
+        pose_jacobian: prediction.pose_jacobian,
+        input_jacobian: j.map(|row| [row[0], row[5]]),
+    }
+}
@@ -0,0 +1,129 @@
+//! Oriented bounding boxes.
+
+use crate::{Aabb, Mat3, Rbt, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// An oriented bounding box.
+pub struct Obb {
+    /// The pose of the box center. Box edges align with its local axes.
+    pub center_pose: Rbt,
+    /// The half extents along the local axes.
+    pub half_extents: Vec3,
+}
+
+impl Obb {
+    /// Creates an OBB from center pose and half extents.
+    pub fn new(center_pose: Rbt, half_extents: Vec3) -> Self {
+        Obb {
+            center_pose,
+            half_extents,
+        }
+    }
+
+    /// Creates an OBB covering exactly `aabb`.
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        Obb {
+            center_pose: Rbt::from_t(aabb.center()),
+            half_extents: aabb.half_extents(),
+        }
+    }
+
+    /// Returns the tightest AABB containing `self`.
+    pub fn to_aabb(&self) -> Aabb {
+        self.center_pose
+            .transform_aabb(&Aabb::from_center_half_extents(
+                Vec3::ZERO,
+                self.half_extents,
+            ))
+    }
+
+    /// Returns whether `point` is inside or on the boundary.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let local = self.center_pose.rotation.conjugate() * (point - self.center_pose.translation);
+        local.abs().cmple(self.half_extents).all()
+    }
+
+    /// Returns whether `self` and `other` overlap, using the separating axis test.
+    pub fn intersects(&self, other: &Obb) -> bool {
+        let a = Mat3::from_quat(self.center_pose.rotation);
+        let b = Mat3::from_quat(other.center_pose.rotation);
+        // Rotation and translation of `other` in the frame of `self`, as rows.
+        let r = (a.transpose() * b).transpose().to_cols_array_2d();
+        let t = (a.transpose() * (other.center_pose.translation - self.center_pose.translation))
+            .to_array();
+        // Padded to stay robust when edges are nearly parallel.
+        let abs_r = r.map(|row| row.map(|x| x.abs() + 1e-6));
+        let ea = self.half_extents.to_array();
+        let eb = other.half_extents.to_array();
+
+        for i in 0..3 {
+            let ra = ea[i];
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let rb = eb[j];
+            let tj = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if tj.abs() > ra + rb {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let tl = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if tl.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns whether `self` and `aabb` overlap.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.intersects(&Obb::from_aabb(aabb))
+    }
+}
+
+impl Mul<&Obb> for &Rbt {
+    type Output = Obb;
+
+    fn mul(self, rhs: &Obb) -> Obb {
+        Obb {
+            center_pose: self * &rhs.center_pose,
+            half_extents: rhs.half_extents,
+        }
+    }
+}
+
+impl Mul<Obb> for &Rbt {
+    type Output = Obb;
+
+    fn mul(self, rhs: Obb) -> Obb {
+        self * &rhs
+    }
+}
+
+impl Mul<&Obb> for Rbt {
+    type Output = Obb;
+
+    fn mul(self, rhs: &Obb) -> Obb {
+        &self * rhs
+    }
+}
+
+impl Mul<Obb> for Rbt {
+    type Output = Obb;
+
+    fn mul(self, rhs: Obb) -> Obb {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,45 @@
+//! Wheel odometry.
+
+use crate::{Plane, Rbt, Rbt2, Vec3};
+
+/// A pose that planar motion steps can be applied to.
+pub trait PlanarPose {
+    /// Returns `self` moved by `step`, given in the local frame.
+    fn then_planar(&self, step: &Rbt2) -> Self;
+}
+
+impl PlanarPose for Rbt2 {
+    fn then_planar(&self, step: &Rbt2) -> Self {
+        self * step
+    }
+}
+
+/// Moves in the local XY plane, rotating around local Z, i.e. X forward and Z up.
+impl PlanarPose for Rbt {
+    fn then_planar(&self, step: &Rbt2) -> Self {
+        self * step.to_rbt(Plane::Xy)
+    }
+}
+
+/// Moves `pose` of a differential drive robot whose left and right wheels traveled `left` and `right`.
+///
+/// The robot moves along the exact circular arc, assuming constant wheel speeds over the step.
+/// `wheel_base` is the distance between the wheels. Local x is forward and y is left.
+pub fn integrate_diff_drive<P: PlanarPose>(pose: &P, left: f32, right: f32, wheel_base: f32) -> P {
+    let distance = (left + right) * 0.5;
+    let angle = (right - left) / wheel_base;
+    pose.then_planar(&Rbt2::exp(Vec3::new(distance, 0.0, angle)))
+}
+
+/// Moves `pose` of a differential drive robot with left and right wheel speeds `left` and `right` for `dt`.
+///
+/// Like `integrate_diff_drive`.
+pub fn integrate_diff_drive_velocity<P: PlanarPose>(
+    pose: &P,
+    left: f32,
+    right: f32,
+    wheel_base: f32,
+    dt: f32,
+) -> P {
+    integrate_diff_drive(pose, left * dt, right * dt, wheel_base)
+}
@@ -0,0 +1,113 @@
+//! Compact pose storage.
+
+use crate::{math, Quat, Rbt, Vec3};
+use half::f16;
+
+/// Maximum value of a 15 bit quantized quaternion component.
+const QUANT_MAX: f32 = 32767.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+/// A rigid body transform packed into 12 bytes.
+///
+/// The translation divided by a caller chosen scale is stored as half floats,
+/// so it covers `±65504 * scale` with a relative precision of about `1 / 2048`.
+/// The rotation is stored as its three smallest components quantized to 15 bits,
+/// plus the index of the dropped largest one, with an error in the order of `1e-4` radians.
+pub struct PackedRbtF16 {
+    /// The translation divided by scale, as half float bits.
+    pub translation: [u16; 3],
+    /// The quantized rotation.
+    pub rotation: [u16; 3],
+}
+
+impl PackedRbtF16 {
+    /// Packs `rbt`, storing its translation divided by `scale`.
+    pub fn pack(rbt: &Rbt, scale: f32) -> Self {
+        let t = rbt.translation / scale;
+        PackedRbtF16 {
+            translation: t.to_array().map(|x| f16::from_f32(x).to_bits()),
+            rotation: pack_quat(rbt.rotation),
+        }
+    }
+
+    /// Unpacks to a `Rbt`, multiplying the translation by `scale`.
+    pub fn unpack(&self, scale: f32) -> Rbt {
+        let t = self.translation.map(|x| f16::from_bits(x).to_f32());
+        Rbt {
+            translation: Vec3::from_array(t) * scale,
+            rotation: unpack_quat(self.rotation),
+        }
+    }
+
+    /// Packs each of `rbts` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn pack_slice(rbts: &[Rbt], scale: f32, out: &mut [PackedRbtF16]) {
+        assert_eq!(rbts.len(), out.len(), "length mismatch");
+        for (rbt, packed) in rbts.iter().zip(out) {
+            *packed = Self::pack(rbt, scale);
+        }
+    }
+
+    /// Unpacks each of `packed` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn unpack_slice(packed: &[PackedRbtF16], scale: f32, out: &mut [Rbt]) {
+        assert_eq!(packed.len(), out.len(), "length mismatch");
+        for (p, rbt) in packed.iter().zip(out) {
+            *rbt = p.unpack(scale);
+        }
+    }
+}
+
+/// Packs a quaternion with the smallest three method.
+///
+/// Bits 45..47 hold the index of the dropped component, followed by three 15 bit components.
+fn pack_quat(q: Quat) -> [u16; 3] {
+    let mut c = q.normalize().to_array();
+    let mut largest = 0;
+    for i in 1..4 {
+        if c[i].abs() > c[largest].abs() {
+            largest = i;
+        }
+    }
+    // q and -q are the same rotation, make the dropped component positive.
+    if c[largest] < 0.0 {
+        c = c.map(|x| -x);
+    }
+
+    let mut bits = largest as u64;
+    for (i, x) in c.iter().enumerate() {
+        if i != largest {
+            let v = ((x * std::f32::consts::SQRT_2 + 1.0) * 0.5 * QUANT_MAX)
+                .round()
+                .clamp(0.0, QUANT_MAX) as u64;
+            bits = (bits << 15) | v;
+        }
+    }
+    [(bits >> 32) as u16, (bits >> 16) as u16, bits as u16]
+}
+
+/// Inverse of `pack_quat`.
+fn unpack_quat(packed: [u16; 3]) -> Quat {
+    let bits = ((packed[0] as u64) << 32) | ((packed[1] as u64) << 16) | packed[2] as u64;
+    let largest = (bits >> 45) as usize & 3;
+    let mut c = [0.0; 4];
+    let mut shift = 45;
+    let mut sum = 0.0;
+    for (i, x) in c.iter_mut().enumerate() {
+        if i != largest {
+            shift -= 15;
+            let v = ((bits >> shift) & 0x7fff) as f32;
+            *x = (v / QUANT_MAX * 2.0 - 1.0) * std::f32::consts::FRAC_1_SQRT_2;
+            sum += *x * *x;
+        }
+    }
+    c[largest] = math::sqrt((1.0 - sum).max(0.0));
+    Quat::from_array(c).normalize()
+}
@@ -0,0 +1,247 @@
+//! Geometric paths through waypoints.
+
+use crate::lie::so3_log;
+use crate::Rbt;
+
+/// The number of chords used to measure a blend.
+const BLEND_CHORDS: usize = 16;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A piece of a `BlendedPath`.
+pub enum PathPiece {
+    /// Straight translation with slerped rotation.
+    Line {
+        /// The pose at the start.
+        start: Rbt,
+        /// The pose at the end.
+        end: Rbt,
+    },
+    /// Quadratic Bézier corner, in translation and, by repeated slerps, in rotation.
+    Blend {
+        /// The pose at the start.
+        start: Rbt,
+        /// The corner waypoint, used as control point.
+        control: Rbt,
+        /// The pose at the end.
+        end: Rbt,
+    },
+}
+
+impl PathPiece {
+    /// Returns the pose at `u` in `[0, 1]`.
+    pub fn sample(&self, u: f32) -> Rbt {
+        match self {
+            PathPiece::Line { start, end } => start.lerp(end, u),
+            PathPiece::Blend {
+                start,
+                control,
+                end,
+            } => start.lerp(control, u).lerp(&control.lerp(end, u), u),
+        }
+    }
+
+    /// Returns the translation arc length plus `rotation_weight` times the rotation angle,
+    /// approximated by chords for blends.
+    pub fn length(&self, rotation_weight: f32) -> f32 {
+        let chord = |a: &Rbt, b: &Rbt| {
+            a.translation.distance(b.translation)
+                + rotation_weight * so3_log(a.rotation.conjugate() * b.rotation).length()
+        };
+        match self {
+            PathPiece::Line { start, end } => chord(start, end),
+            PathPiece::Blend { .. } => (0..BLEND_CHORDS)
+                .map(|k| {
+                    let a = self.sample(k as f32 / BLEND_CHORDS as f32);
+                    let b = self.sample((k + 1) as f32 / BLEND_CHORDS as f32);
+                    chord(&a, &b)
+                })
+                .sum(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Builds a `BlendedPath` from waypoints with blend radii.
+pub struct PathBuilder {
+    waypoints: Vec<(Rbt, f32)>,
+    rotation_weight: f32,
+}
+
+impl Default for PathBuilder {
+    fn default() -> Self {
+        PathBuilder {
+            waypoints: Vec::new(),
+            rotation_weight: 1.0,
+        }
+    }
+}
+
+impl PathBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends a waypoint whose corner is rounded within `blend_radius` of it.
+    ///
+    /// The radius is ignored at the first and last waypoints.
+    pub fn waypoint(&mut self, rbt: Rbt, blend_radius: f32) -> &mut Self {
+        self.waypoints.push((rbt, blend_radius));
+        self
+    }
+
+    /// Sets the length a radian of rotation counts as when measuring the path, 1 by default.
+    ///
+    /// With 0, pieces without translation have zero length and are skipped over.
+    pub fn rotation_weight(&mut self, rotation_weight: f32) -> &mut Self {
+        self.rotation_weight = rotation_weight;
+        self
+    }
+
+    /// Builds the path.
+    ///
+    /// Each blend radius is clipped to half the translation length of both adjacent segments,
+    /// and the blend enters and leaves the segments at the same fraction in rotation as in translation.
+    pub fn build(&self) -> BlendedPath {
+        let n = self.waypoints.len();
+        let pose = |i: usize| &self.waypoints[i].0;
+        // Entry and exit poses of the corner at each waypoint.
+        let corners: Vec<(Rbt, Rbt)> = (0..n)
+            .map(|i| {
+                if i == 0 || i + 1 == n {
+                    return (pose(i).clone(), pose(i).clone());
+                }
+                let len_in = pose(i - 1).translation.distance(pose(i).translation);
+                let len_out = pose(i).translation.distance(pose(i + 1).translation);
+                let r = self.waypoints[i].1.min(len_in * 0.5).min(len_out * 0.5);
+                if r <= 0.0 {
+                    return (pose(i).clone(), pose(i).clone());
+                }
+                (
+                    pose(i - 1).lerp(pose(i), 1.0 - r / len_in),
+                    pose(i).lerp(pose(i + 1), r / len_out),
+                )
+            })
+            .collect();
+        let mut pieces = Vec::new();
+        for i in 0..n.saturating_sub(1) {
+            pieces.push(PathPiece::Line {
+                start: corners[i].1.clone(),
+                end: corners[i + 1].0.clone(),
+            });
+            if corners[i + 1].0 != corners[i + 1].1 {
+                pieces.push(PathPiece::Blend {
+                    start: corners[i + 1].0.clone(),
+                    control: pose(i + 1).clone(),
+                    end: corners[i + 1].1.clone(),
+                });
+            }
+        }
+        let mut start = 0.0;
+        let starts = pieces
+            .iter()
+            .map(|p| {
+                let s = start;
+                start += p.length(self.rotation_weight);
+                s
+            })
+            .collect();
+        BlendedPath {
+            pieces,
+            starts,
+            length: start,
+            end: self
+                .waypoints
+                .last()
+                .map(|w| w.0.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A path through waypoints with rounded corners, continuous in translation and rotation.
+pub struct BlendedPath {
+    pieces: Vec<PathPiece>,
+    /// The length at the start of each piece.
+    starts: Vec<f32>,
+    length: f32,
+    end: Rbt,
+}
+
+impl BlendedPath {
+    /// Returns the pieces, alternating between lines and blends.
+    pub fn pieces(&self) -> &[PathPiece] {
+        &self.pieces
+    }
+
+    /// Returns the length, the translation arc length plus the weighted rotation angle.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// Returns the pose at length `s`, clamped to the path ends.
+    ///
+    /// Pieces of zero length are skipped over.
+    pub fn sample(&self, s: f32) -> Rbt {
+        let i = self.starts.partition_point(|start| *start <= s);
+        let Some(i) = i.checked_sub(1) else {
+            return self
+                .pieces
+                .first()
+                .map_or(self.end.clone(), |p| p.sample(0.0));
+        };
+        if s >= self.length {
+            return self.end.clone();
+        }
+        let end = self.starts.get(i + 1).copied().unwrap_or(self.length);
+        self.pieces[i].sample((s - self.starts[i]) / (end - self.starts[i]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Quat, Vec3};
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn rotation_only_segment_is_sampled() {
+        let turned = Quat::from_rotation_z(FRAC_PI_2);
+        let path = PathBuilder::new()
+            .waypoint(Rbt::new(), 0.0)
+            .waypoint(Rbt::from_r(turned), 0.2)
+            .waypoint(Rbt::from_t_r(Vec3::X, turned), 0.0)
+            .build();
+        assert!((path.length() - (FRAC_PI_2 + 1.0)).abs() < 1e-5);
+
+        let halfway = path.sample(FRAC_PI_2 * 0.5);
+        assert!(halfway.translation.length() < 1e-6);
+        let expected = Quat::from_rotation_z(FRAC_PI_2 * 0.5);
+        assert!(halfway.rotation.dot(expected).abs() > 1.0 - 1e-6);
+
+        // No jumps in translation or rotation along the whole path.
+        let steps = 1000;
+        for k in 0..steps {
+            let a = path.sample(path.length() * k as f32 / steps as f32);
+            let b = path.sample(path.length() * (k + 1) as f32 / steps as f32);
+            let angle = so3_log(a.rotation.conjugate() * b.rotation).length();
+            assert!(
+                a.translation.distance(b.translation) + angle < 1e-2,
+                "jump at {k}"
+            );
+        }
+    }
+
+    #[test]
+    fn pure_rotation_path_is_interpolated() {
+        let end = Rbt::from_r(Quat::from_rotation_x(1.0));
+        let path = PathBuilder::new()
+            .waypoint(Rbt::new(), 0.0)
+            .waypoint(end.clone(), 0.0)
+            .build();
+        let halfway = path.sample(path.length() * 0.5);
+        assert!(halfway.rotation.dot(Quat::from_rotation_x(0.5)).abs() > 1.0 - 1e-6);
+        assert_eq!(path.sample(path.length()), end);
+    }
+}
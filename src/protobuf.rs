@@ -0,0 +1,213 @@
+//! Protobuf pose messages.
+//!
+//! The schema is in `proto/rbt3.proto`, so other services can generate compatible types.
+
+use crate::uncertainty::{CovarianceLenError, UncertainRbt};
+use crate::{Quat, Rbt, StampedRbt, Vec3};
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+/// A 3D vector.
+pub struct Vector3 {
+    /// X.
+    #[prost(double, tag = "1")]
+    pub x: f64,
+    /// Y.
+    #[prost(double, tag = "2")]
+    pub y: f64,
+    /// Z.
+    #[prost(double, tag = "3")]
+    pub z: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+/// A rotation quaternion.
+pub struct Quaternion {
+    /// X.
+    #[prost(double, tag = "1")]
+    pub x: f64,
+    /// Y.
+    #[prost(double, tag = "2")]
+    pub y: f64,
+    /// Z.
+    #[prost(double, tag = "3")]
+    pub z: f64,
+    /// W.
+    #[prost(double, tag = "4")]
+    pub w: f64,
+}
+
+#[derive(Clone, Copy, PartialEq, prost::Message)]
+/// A pose.
+pub struct Pose {
+    /// The translation.
+    #[prost(message, optional, tag = "1")]
+    pub position: Option<Vector3>,
+    /// The rotation.
+    #[prost(message, optional, tag = "2")]
+    pub orientation: Option<Quaternion>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+/// A pose with uncertainty.
+pub struct PoseWithCovariance {
+    /// The pose.
+    #[prost(message, optional, tag = "1")]
+    pub pose: Option<Pose>,
+    /// Row major 6x6 covariance of the right perturbation, as in [`UncertainRbt`].
+    #[prost(double, repeated, tag = "2")]
+    pub covariance: Vec<f64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+/// A timestamped pose between two named frames.
+pub struct StampedPose {
+    /// The time in seconds.
+    #[prost(double, tag = "1")]
+    pub time: f64,
+    /// The frame the pose is expressed in.
+    #[prost(string, tag = "2")]
+    pub frame: String,
+    /// The frame whose pose this is.
+    #[prost(string, tag = "3")]
+    pub child_frame: String,
+    /// The pose.
+    #[prost(message, optional, tag = "4")]
+    pub pose: Option<Pose>,
+}
+
+impl From<&Rbt> for Pose {
+    fn from(rbt: &Rbt) -> Self {
+        let (t, r) = (rbt.translation, rbt.rotation);
+        Pose {
+            position: Some(Vector3 {
+                x: t.x as f64,
+                y: t.y as f64,
+                z: t.z as f64,
+            }),
+            orientation: Some(Quaternion {
+                x: r.x as f64,
+                y: r.y as f64,
+                z: r.z as f64,
+                w: r.w as f64,
+            }),
+        }
+    }
+}
+
+impl From<&Pose> for Rbt {
+    /// Missing fields are taken as identity.
+    fn from(pose: &Pose) -> Self {
+        let t = pose.position.unwrap_or_default();
+        let rotation = match pose.orientation {
+            Some(r) => Quat::from_xyzw(r.x as f32, r.y as f32, r.z as f32, r.w as f32).normalize(),
+            None => Quat::IDENTITY,
+        };
+        Rbt::from_t_r(Vec3::new(t.x as f32, t.y as f32, t.z as f32), rotation)
+    }
+}
+
+impl From<&UncertainRbt> for PoseWithCovariance {
+    fn from(uncertain: &UncertainRbt) -> Self {
+        PoseWithCovariance {
+            pose: Some((&uncertain.mean).into()),
+            covariance: uncertain
+                .covariance
+                .iter()
+                .flatten()
+                .map(|&x| x as f64)
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&PoseWithCovariance> for UncertainRbt {
+    type Error = CovarianceLenError;
+
+    /// A missing pose is taken as identity.
+    fn try_from(message: &PoseWithCovariance) -> Result<Self, Self::Error> {
+        let covariance: &[f64; 36] =
+            message
+                .covariance
+                .as_slice()
+                .try_into()
+                .map_err(|_| CovarianceLenError {
+                    len: message.covariance.len(),
+                })?;
+        Ok(UncertainRbt::new(
+            message.pose.as_ref().map(Rbt::from).unwrap_or_default(),
+            core::array::from_fn(|i| core::array::from_fn(|j| covariance[6 * i + j] as f32)),
+        ))
+    }
+}
+
+impl From<&StampedRbt> for StampedPose {
+    /// Frame names are left empty.
+    fn from(stamped: &StampedRbt) -> Self {
+        StampedPose {
+            time: stamped.time,
+            pose: Some((&stamped.rbt).into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&StampedPose> for StampedRbt {
+    fn from(stamped: &StampedPose) -> Self {
+        StampedRbt::new(
+            stamped.time,
+            stamped.pose.as_ref().map(Rbt::from).unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    fn rbt() -> Rbt {
+        Rbt::from_t_r(
+            Vec3::new(1.0, -2.0, 0.5),
+            Quat::from_axis_angle(Vec3::new(0.3, -0.5, 0.8).normalize(), 1.2),
+        )
+    }
+
+    #[test]
+    fn rbt_round_trips() {
+        let bytes = Pose::from(&rbt()).encode_to_vec();
+        let decoded = Rbt::from(&Pose::decode(bytes.as_slice()).unwrap());
+        assert_eq!(decoded.translation, rbt().translation);
+        assert!(decoded.rotation.abs_diff_eq(rbt().rotation, 1e-7));
+    }
+
+    #[test]
+    fn stamped_round_trips() {
+        let stamped = StampedRbt::new(12.25, rbt());
+        let bytes = StampedPose::from(&stamped).encode_to_vec();
+        let decoded = StampedRbt::from(&StampedPose::decode(bytes.as_slice()).unwrap());
+        assert_eq!(decoded.time, stamped.time);
+        assert_eq!(decoded.rbt.translation, stamped.rbt.translation);
+        assert!(decoded.rbt.rotation.abs_diff_eq(stamped.rbt.rotation, 1e-7));
+    }
+
+    #[test]
+    fn uncertain_round_trips() {
+        let covariance = core::array::from_fn(|i| core::array::from_fn(|j| (i * 6 + j) as f32));
+        let uncertain = UncertainRbt::new(rbt(), covariance);
+        let bytes = PoseWithCovariance::from(&uncertain).encode_to_vec();
+        let message = PoseWithCovariance::decode(bytes.as_slice()).unwrap();
+        let decoded = UncertainRbt::try_from(&message).unwrap();
+        assert_eq!(decoded.covariance, covariance);
+        assert_eq!(decoded.mean.translation, uncertain.mean.translation);
+    }
+
+    #[test]
+    fn rejects_wrong_covariance_length() {
+        let mut message = PoseWithCovariance::from(&UncertainRbt::default());
+        message.covariance.truncate(35);
+        assert_eq!(
+            UncertainRbt::try_from(&message),
+            Err(CovarianceLenError { len: 35 })
+        );
+    }
+}
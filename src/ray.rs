@@ -0,0 +1,73 @@
+//! Rays.
+
+use crate::{Rbt, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A ray, the set of points `origin + t * dir` for non-negative `t`.
+pub struct Ray {
+    /// The origin of the ray.
+    pub origin: Vec3,
+    /// The direction of the ray. Not necessarily normalized.
+    pub dir: Vec3,
+}
+
+impl Ray {
+    /// Creates a ray from origin and direction.
+    pub fn new(origin: Vec3, dir: Vec3) -> Self {
+        Ray { origin, dir }
+    }
+
+    /// Returns the point at parameter `t`.
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.dir * t
+    }
+}
+
+impl Rbt {
+    /// Transforms `ray` by the inverse of `self`, e.g. from world space to object space.
+    ///
+    /// Ray parameters are preserved.
+    pub fn inverse_transform_ray(&self, ray: &Ray) -> Ray {
+        let inv_r = self.rotation.conjugate();
+        Ray {
+            origin: inv_r * (ray.origin - self.translation),
+            dir: inv_r * ray.dir,
+        }
+    }
+}
+
+impl Mul<&Ray> for &Rbt {
+    type Output = Ray;
+
+    fn mul(self, rhs: &Ray) -> Ray {
+        Ray {
+            origin: self.rotation * rhs.origin + self.translation,
+            dir: self.rotation * rhs.dir,
+        }
+    }
+}
+
+impl Mul<Ray> for &Rbt {
+    type Output = Ray;
+
+    fn mul(self, rhs: Ray) -> Ray {
+        self * &rhs
+    }
+}
+
+impl Mul<&Ray> for Rbt {
+    type Output = Ray;
+
+    fn mul(self, rhs: &Ray) -> Ray {
+        &self * rhs
+    }
+}
+
+impl Mul<Ray> for Rbt {
+    type Output = Ray;
+
+    fn mul(self, rhs: Ray) -> Ray {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,178 @@
+//! Planar rigid body transforms.
+
+use crate::{math, Quat, Rbt, Vec2, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A rigid body transform in 2D.
+pub struct Rbt2 {
+    /// The translation part.
+    pub translation: Vec2,
+    /// The rotation angle in radians, counterclockwise, in `[-π, π)`.
+    pub angle: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A coordinate plane of 3D space to embed 2D transforms in.
+///
+/// 2D x and y map to the first and second named axes, and rotation is around the remaining axis.
+pub enum Plane {
+    /// 2D x is X, y is Y, rotation is around Z.
+    Xy,
+    /// 2D x is Y, y is Z, rotation is around X.
+    Yz,
+    /// 2D x is Z, y is X, rotation is around Y. The ground plane when Y is up.
+    Zx,
+}
+
+impl Plane {
+    /// Returns the 3D axes of 2D x and y, and the rotation axis.
+    fn axes(self) -> (Vec3, Vec3, Vec3) {
+        match self {
+            Plane::Xy => (Vec3::X, Vec3::Y, Vec3::Z),
+            Plane::Yz => (Vec3::Y, Vec3::Z, Vec3::X),
+            Plane::Zx => (Vec3::Z, Vec3::X, Vec3::Y),
+        }
+    }
+}
+
+impl Rbt2 {
+    /// Returns the identity transform.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a Rbt2 from translation and angle.
+    pub fn from_t_r(translation: Vec2, angle: f32) -> Self {
+        Rbt2 {
+            translation,
+            angle: math::wrap_angle(angle),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let inv_angle = math::wrap_angle(-self.angle);
+        Rbt2 {
+            translation: -rotate(inv_angle, self.translation),
+            angle: inv_angle,
+        }
+    }
+
+    /// Transforms a point.
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        rotate(self.angle, point) + self.translation
+    }
+
+    /// Rotates a direction.
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        rotate(self.angle, vector)
+    }
+
+    /// Returns the transform of the twist `(vx, vy, ω)` integrated over unit time.
+    pub fn exp(twist: Vec3) -> Self {
+        let (a, b) = v_coefficients(twist.z);
+        let v = twist.truncate();
+        Rbt2::from_t_r(Vec2::new(a * v.x - b * v.y, b * v.x + a * v.y), twist.z)
+    }
+
+    /// Returns the twist `(vx, vy, ω)` that `exp` maps to `self`.
+    pub fn log(&self) -> Vec3 {
+        let (a, b) = v_coefficients(self.angle);
+        let t = self.translation;
+        let d = a * a + b * b;
+        Vec3::new((a * t.x + b * t.y) / d, (a * t.y - b * t.x) / d, self.angle)
+    }
+
+    /// Interpolates along the constant twist path from `self` at `t = 0` to `to` at `t = 1`.
+    pub fn interpolate(&self, to: &Rbt2, t: f32) -> Rbt2 {
+        self * Rbt2::exp((self.inverse() * to).log() * t)
+    }
+
+    /// Embeds `self` in 3D on `plane`.
+    pub fn to_rbt(&self, plane: Plane) -> Rbt {
+        let (u, v, n) = plane.axes();
+        Rbt::from_t_r(
+            u * self.translation.x + v * self.translation.y,
+            Quat::from_axis_angle(n, self.angle),
+        )
+    }
+
+    /// Projects `rbt` onto `plane`, keeping the rotation angle around the plane normal.
+    pub fn from_rbt(rbt: &Rbt, plane: Plane) -> Self {
+        let (u, v, _) = plane.axes();
+        let x = rbt.rotation * u;
+        Rbt2 {
+            translation: Vec2::new(rbt.translation.dot(u), rbt.translation.dot(v)),
+            angle: math::atan2(x.dot(v), x.dot(u)),
+        }
+    }
+}
+
+fn rotate(angle: f32, v: Vec2) -> Vec2 {
+    let (s, c) = (math::sin(angle), math::cos(angle));
+    Vec2::new(c * v.x - s * v.y, s * v.x + c * v.y)
+}
+
+/// Returns `(sin θ / θ, (1 - cos θ) / θ)`, which make up the left Jacobian of SO(2).
+fn v_coefficients(theta: f32) -> (f32, f32) {
+    if theta.abs() < 1e-4 {
+        let t2 = theta * theta;
+        (1.0 - t2 / 6.0, theta * (0.5 - t2 / 24.0))
+    } else {
+        (math::sin(theta) / theta, (1.0 - math::cos(theta)) / theta)
+    }
+}
+
+impl Mul<&Rbt2> for &Rbt2 {
+    type Output = Rbt2;
+
+    // Rotation angles add under composition.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &Rbt2) -> Rbt2 {
+        Rbt2 {
+            translation: self.transform_point(rhs.translation),
+            angle: math::wrap_angle(self.angle + rhs.angle),
+        }
+    }
+}
+
+impl Mul<Rbt2> for &Rbt2 {
+    type Output = Rbt2;
+
+    fn mul(self, rhs: Rbt2) -> Rbt2 {
+        self * &rhs
+    }
+}
+
+impl Mul<Vec3> for &Rbt2 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        (self.transform_vector(rhs.truncate()) + self.translation * rhs.z).extend(rhs.z)
+    }
+}
+
+impl Mul<&Rbt2> for Rbt2 {
+    type Output = Rbt2;
+
+    fn mul(self, rhs: &Rbt2) -> Rbt2 {
+        &self * rhs
+    }
+}
+
+impl Mul<Rbt2> for Rbt2 {
+    type Output = Rbt2;
+
+    fn mul(self, rhs: Rbt2) -> Rbt2 {
+        &self * &rhs
+    }
+}
+
+impl Mul<Vec3> for Rbt2 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        &self * rhs
+    }
+}
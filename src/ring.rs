@@ -0,0 +1,102 @@
+//! Fixed capacity pose history.
+
+use crate::{Rbt, StampedRbt};
+
+#[derive(Debug, Clone)]
+/// A ring buffer of the latest `N` timestamped poses, with interpolated lookup by time.
+///
+/// Never allocates. Poses must be pushed in nondecreasing time order.
+pub struct PoseRingBuffer<const N: usize> {
+    poses: [StampedRbt; N],
+    /// Index of the oldest pose.
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for PoseRingBuffer<N> {
+    fn default() -> Self {
+        PoseRingBuffer {
+            poses: core::array::from_fn(|_| StampedRbt::default()),
+            start: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> PoseRingBuffer<N> {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the number of stored poses.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no pose is stored.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all poses.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Adds a pose, dropping the oldest one if full.
+    pub fn push(&mut self, pose: StampedRbt) {
+        if N == 0 {
+            return;
+        }
+        if self.len < N {
+            self.poses[(self.start + self.len) % N] = pose;
+            self.len += 1;
+        } else {
+            self.poses[self.start] = pose;
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    /// Returns the `i`th oldest pose.
+    pub fn get(&self, i: usize) -> Option<&StampedRbt> {
+        (i < self.len).then(|| &self.poses[(self.start + i) % N])
+    }
+
+    /// Returns the oldest pose.
+    pub fn oldest(&self) -> Option<&StampedRbt> {
+        self.get(0)
+    }
+
+    /// Returns the latest pose.
+    pub fn latest(&self) -> Option<&StampedRbt> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns the pose at `time`, interpolated between the stored poses around it.
+    ///
+    /// Returns `None` if `time` is NaN or outside the stored time range.
+    pub fn lookup(&self, time: f64) -> Option<Rbt> {
+        let (first, last) = (self.oldest()?, self.latest()?);
+        if !(first.time..=last.time).contains(&time) {
+            return None;
+        }
+        // Index of the first pose later than `time`.
+        let (mut lo, mut hi) = (0, self.len);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.get(mid)?.time <= time {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == self.len {
+            return Some(last.rbt.clone());
+        }
+        let (a, b) = (self.get(lo - 1)?, self.get(lo)?);
+        let t = ((time - a.time) / (b.time - a.time)) as f32;
+        Some(a.rbt.lerp(&b.rbt, t))
+    }
+}
@@ -0,0 +1,46 @@
+//! A small deterministic pseudo random number generator.
+
+use crate::{math, Vec3};
+
+#[derive(Debug, Clone)]
+/// A xorshift64* generator. Not cryptographically secure.
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator from `seed`. Any seed is fine, including 0.
+    pub(crate) fn new(seed: u64) -> Self {
+        // SplitMix64 scrambles the seed so similar seeds give unrelated streams.
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns a uniform index in `0..n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (((self.next_u64() >> 32) * n as u64) >> 32) as usize
+    }
+
+    /// Returns a uniform value in `(0, 1]`.
+    pub(crate) fn uniform(&mut self) -> f32 {
+        ((self.next_u64() >> 40) + 1) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a standard normal value, by Box-Muller.
+    pub(crate) fn gaussian(&mut self) -> f32 {
+        let (u, v) = (self.uniform(), self.uniform());
+        math::sqrt(-2.0 * math::ln(u)) * math::cos(std::f32::consts::TAU * v)
+    }
+
+    /// Returns a vector of independent standard normal values.
+    pub(crate) fn gaussian_vec3(&mut self) -> Vec3 {
+        Vec3::new(self.gaussian(), self.gaussian(), self.gaussian())
+    }
+}
@@ -0,0 +1,293 @@
+//! Scene graphs of named frames.
+
+use crate::{Mat3, Quat, Rbt, Vec3};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// A node of a `SceneGraph`.
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the index of the node, in order of creation.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Node {
+    parent: Option<NodeId>,
+    local: Rbt,
+    parent_inverse: Rbt,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A tree of frames, each with a local transform relative to its parent.
+///
+/// The world transform of a node is `world(parent) * parent_inverse * local`. The parent inverse is an extra
+/// stored offset, identity unless set, which lets a node be parented without changing its local transform.
+pub struct SceneGraph {
+    nodes: Vec<Node>,
+}
+
+impl SceneGraph {
+    /// Creates an empty scene graph.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether there are no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a node with local transform `local` under `parent`, or as a root.
+    pub fn add(&mut self, parent: Option<NodeId>, local: Rbt) -> NodeId {
+        self.nodes.push(Node {
+            parent,
+            local,
+            parent_inverse: Rbt::new(),
+        });
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Returns the parent of `node`.
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.nodes[node.0].parent
+    }
+
+    /// Returns the children of `node`, in order of creation.
+    pub fn children(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len())
+            .map(NodeId)
+            .filter(move |&n| self.nodes[n.0].parent == Some(node))
+    }
+
+    /// Returns the local transform of `node`.
+    pub fn local(&self, node: NodeId) -> &Rbt {
+        &self.nodes[node.0].local
+    }
+
+    /// Sets the local transform of `node`.
+    pub fn set_local(&mut self, node: NodeId, local: Rbt) {
+        self.nodes[node.0].local = local;
+    }
+
+    /// Returns the parent inverse of `node`.
+    pub fn parent_inverse(&self, node: NodeId) -> &Rbt {
+        &self.nodes[node.0].parent_inverse
+    }
+
+    /// Sets the parent inverse of `node`.
+    pub fn set_parent_inverse(&mut self, node: NodeId, parent_inverse: Rbt) {
+        self.nodes[node.0].parent_inverse = parent_inverse;
+    }
+
+    /// Returns the transform of the frame `node`'s local transform is relative to, `world(parent) * parent_inverse`.
+    pub fn parent_world(&self, node: NodeId) -> Rbt {
+        let n = &self.nodes[node.0];
+        match n.parent {
+            Some(p) => self.world(p) * &n.parent_inverse,
+            None => n.parent_inverse.clone(),
+        }
+    }
+
+    /// Returns the world transform of `node`.
+    pub fn world(&self, node: NodeId) -> Rbt {
+        let mut world = self.nodes[node.0].local.clone();
+        let mut current = node;
+        loop {
+            let n = &self.nodes[current.0];
+            world = &n.parent_inverse * world;
+            match n.parent {
+                Some(p) => {
+                    world = &self.nodes[p.0].local * world;
+                    current = p;
+                }
+                None => return world,
+            }
+        }
+    }
+
+    /// Returns whether `node` is `ancestor` or one of its descendants.
+    pub fn is_descendant(&self, node: NodeId, ancestor: NodeId) -> bool {
+        let mut current = Some(node);
+        while let Some(n) = current {
+            if n == ancestor {
+                return true;
+            }
+            current = self.nodes[n.0].parent;
+        }
+        false
+    }
+
+    /// Moves `child` under `parent`, or makes it a root, keeping its local transform and parent inverse.
+    ///
+    /// The world transform changes with the parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is `child` or one of its descendants.
+    pub fn set_parent(&mut self, child: NodeId, parent: Option<NodeId>) {
+        if let Some(p) = parent {
+            assert!(!self.is_descendant(p, child), "cycle in scene graph");
+        }
+        self.nodes[child.0].parent = parent;
+    }
+
+    /// Moves `child` under `parent`, or makes it a root, recomputing its local transform so its world
+    /// transform is unchanged. The parent inverse is kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is `child` or one of its descendants.
+    pub fn set_parent_keep_world(&mut self, child: NodeId, parent: Option<NodeId>) {
+        let world = self.world(child);
+        self.set_parent(child, parent);
+        self.nodes[child.0].local = self.parent_world(child).inverse() * world;
+    }
+
+    /// Moves `child` under `parent`, or makes it a root, keeping both its local and world transforms by
+    /// recomputing its parent inverse instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is `child` or one of its descendants.
+    pub fn set_parent_keep_world_with_inverse(&mut self, child: NodeId, parent: Option<NodeId>) {
+        let world = self.world(child);
+        self.set_parent(child, parent);
+        let parent_world = parent.map(|p| self.world(p)).unwrap_or_default();
+        let n = &mut self.nodes[child.0];
+        n.parent_inverse = parent_world.inverse() * world * n.local.inverse();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A rigging constraint on the world transform of a node, driven by a target node.
+///
+/// Each blends from its input at influence 0 to its result at influence 1.
+pub enum NodeConstraint {
+    /// Copies the world translation of `target`.
+    CopyLocation {
+        /// The node copied from.
+        target: NodeId,
+        /// The blend weight.
+        influence: f32,
+    },
+    /// Copies the world rotation of `target`.
+    CopyRotation {
+        /// The node copied from.
+        target: NodeId,
+        /// The blend weight.
+        influence: f32,
+    },
+    /// Follows `target` as if parented to it, with the world transform `world(target) * offset`.
+    ChildOf {
+        /// The node followed.
+        target: NodeId,
+        /// The transform relative to `target`.
+        offset: Rbt,
+        /// The blend weight.
+        influence: f32,
+    },
+    /// Rotates so local `axis` points at the world translation of `target`, keeping local `up` as close to
+    /// world `world_up` as possible.
+    Aim {
+        /// The node aimed at.
+        target: NodeId,
+        /// The local axis to aim, unit length.
+        axis: Vec3,
+        /// The local axis to keep up, unit length and not parallel to `axis`.
+        up: Vec3,
+        /// The world up direction.
+        world_up: Vec3,
+        /// The blend weight.
+        influence: f32,
+    },
+}
+
+impl NodeConstraint {
+    /// Applies `self` to world transform `world`, reading targets from `graph`.
+    pub fn apply(&self, graph: &SceneGraph, world: &Rbt) -> Rbt {
+        match self {
+            NodeConstraint::CopyLocation { target, influence } => {
+                let translation = graph.world(*target).translation;
+                Rbt::from_t_r(
+                    world.translation.lerp(translation, *influence),
+                    world.rotation,
+                )
+            }
+            NodeConstraint::CopyRotation { target, influence } => {
+                let rotation = graph.world(*target).rotation;
+                Rbt::from_t_r(
+                    world.translation,
+                    world.rotation.slerp(rotation, *influence),
+                )
+            }
+            NodeConstraint::ChildOf {
+                target,
+                offset,
+                influence,
+            } => world.lerp(&(graph.world(*target) * offset), *influence),
+            NodeConstraint::Aim {
+                target,
+                axis,
+                up,
+                world_up,
+                influence,
+            } => {
+                let forward =
+                    (graph.world(*target).translation - world.translation).normalize_or_zero();
+                if forward == Vec3::ZERO {
+                    return world.clone();
+                }
+                let frame = |a: Vec3, u: Vec3| {
+                    let side = a.cross(u).normalize_or_zero();
+                    (side != Vec3::ZERO).then(|| Mat3::from_cols(a, side.cross(a), side))
+                };
+                let rotation = match (frame(forward, *world_up), frame(*axis, *up)) {
+                    (Some(w), Some(l)) => Quat::from_mat3(&(w * l.transpose())),
+                    // Looking along the up direction, so only the shortest arc is defined.
+                    _ => Quat::from_rotation_arc(world.rotation * *axis, forward) * world.rotation,
+                };
+                Rbt::from_t_r(
+                    world.translation,
+                    world.rotation.slerp(rotation, *influence),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Constraints applied in order to the world transform of a node.
+pub struct ConstraintStack {
+    /// The constraints, first applied first.
+    pub constraints: Vec<NodeConstraint>,
+}
+
+impl ConstraintStack {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends `constraint`.
+    pub fn push(&mut self, constraint: NodeConstraint) -> &mut Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Returns the world transform of `node` after applying the constraints.
+    ///
+    /// Targets are read unconstrained from `graph`.
+    pub fn evaluate(&self, graph: &SceneGraph, node: NodeId) -> Rbt {
+        self.constraints
+            .iter()
+            .fold(graph.world(node), |world, c| c.apply(graph, &world))
+    }
+}
@@ -0,0 +1,40 @@
+//! Serde support with a fixed, documented data model.
+//!
+//! `Rbt` serializes as the tuple `(tx, ty, tz, qx, qy, qz, qw)` of `f32`s,
+//! and `StampedRbt` as the tuple `(time, rbt)` with `time` an `f64`.
+//!
+//! Deserializing normalizes the rotation, like the other decoders in this crate.
+
+use crate::{Quat, Rbt, StampedRbt, Vec3};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Rbt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (t, r) = (self.translation, self.rotation);
+        (t.x, t.y, t.z, r.x, r.y, r.z, r.w).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rbt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tx, ty, tz, qx, qy, qz, qw) =
+            <(f32, f32, f32, f32, f32, f32, f32)>::deserialize(deserializer)?;
+        Ok(Rbt::from_t_r(
+            Vec3::new(tx, ty, tz),
+            Quat::from_xyzw(qx, qy, qz, qw).normalize(),
+        ))
+    }
+}
+
+impl Serialize for StampedRbt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.time, &self.rbt).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StampedRbt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (time, rbt) = <(f64, Rbt)>::deserialize(deserializer)?;
+        Ok(StampedRbt { time, rbt })
+    }
+}
@@ -0,0 +1,141 @@
+//! Similarity transforms.
+
+use crate::{Mat4, Rbt, Vec3, Vec4};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A similarity transform, a rigid body transform with uniform scale.
+///
+/// Maps `p` to `rbt.rotation * (scale * p) + rbt.translation`.
+pub struct Sim3 {
+    /// The uniform scale, applied first.
+    pub scale: f32,
+    /// The rigid part, applied after scaling.
+    pub rbt: Rbt,
+}
+
+impl Default for Sim3 {
+    fn default() -> Self {
+        Sim3 {
+            scale: 1.0,
+            rbt: Rbt::new(),
+        }
+    }
+}
+
+impl From<Rbt> for Sim3 {
+    fn from(rbt: Rbt) -> Self {
+        Sim3 { scale: 1.0, rbt }
+    }
+}
+
+impl Sim3 {
+    /// Returns the identity transform.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a Sim3 from scale and rigid part.
+    pub fn from_scale_rbt(scale: f32, rbt: Rbt) -> Self {
+        Sim3 { scale, rbt }
+    }
+
+    /// Returns the rigid part, discarding the scale.
+    pub fn into_rbt(self) -> Rbt {
+        self.rbt
+    }
+
+    /// Converts from a 4x4 matrix.
+    ///
+    /// Takes the scale from the length of the first column. Won't check for uniform scale or rigidness.
+    pub fn from_mat4(mat4: &Mat4) -> Self {
+        let scale = mat4.x_axis.truncate().length();
+        Sim3 {
+            scale,
+            rbt: Rbt::from_mat4(&(*mat4 * Mat4::from_scale(Vec3::splat(1.0 / scale)))),
+        }
+    }
+
+    /// Converts to a 4x4 matrix.
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            Vec3::splat(self.scale),
+            self.rbt.rotation,
+            self.rbt.translation,
+        )
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let inv_s = 1.0 / self.scale;
+        let inv_r = self.rbt.rotation.conjugate();
+        Sim3 {
+            scale: inv_s,
+            rbt: Rbt::from_t_r(-(inv_r * self.rbt.translation) * inv_s, inv_r),
+        }
+    }
+
+    /// Transforms a point.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rbt.rotation * (point * self.scale) + self.rbt.translation
+    }
+
+    /// Transforms a direction, scaling and rotating it.
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rbt.rotation * (vector * self.scale)
+    }
+}
+
+impl Mul<&Sim3> for &Sim3 {
+    type Output = Sim3;
+
+    fn mul(self, rhs: &Sim3) -> Sim3 {
+        Sim3 {
+            scale: self.scale * rhs.scale,
+            rbt: Rbt::from_t_r(
+                self.transform_point(rhs.rbt.translation),
+                (self.rbt.rotation * rhs.rbt.rotation).normalize(),
+            ),
+        }
+    }
+}
+
+impl Mul<Sim3> for &Sim3 {
+    type Output = Sim3;
+
+    fn mul(self, rhs: Sim3) -> Sim3 {
+        self * &rhs
+    }
+}
+
+impl Mul<&Sim3> for Sim3 {
+    type Output = Sim3;
+
+    fn mul(self, rhs: &Sim3) -> Sim3 {
+        &self * rhs
+    }
+}
+
+impl Mul<Sim3> for Sim3 {
+    type Output = Sim3;
+
+    fn mul(self, rhs: Sim3) -> Sim3 {
+        &self * &rhs
+    }
+}
+
+impl Mul<Vec4> for &Sim3 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        (self.transform_vector(rhs.truncate()) + self.rbt.translation * rhs.w).extend(rhs.w)
+    }
+}
+
+impl Mul<Vec4> for Sim3 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        &self * rhs
+    }
+}
@@ -0,0 +1,78 @@
+//! Synthetic noise for generating test data.
+
+use crate::lie::so3_log;
+use crate::rng::Rng;
+use crate::{math, Rbt, StampedRbt, Twist, Vec3};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Odometry noise, applied to each relative step of a trajectory in the body frame.
+///
+/// Random errors grow with the square root of the motion, biases linearly with the distance traveled.
+pub struct OdometryNoise {
+    /// Translation standard deviation per square root meter traveled, per axis.
+    pub translation_per_meter: f32,
+    /// Rotation standard deviation in radians per square root meter traveled, per axis.
+    pub rotation_per_meter: f32,
+    /// Rotation standard deviation per square root radian rotated, per axis.
+    pub rotation_per_radian: f32,
+    /// Translation error per meter traveled, in the body frame. E.g. wheel scale errors.
+    pub translation_bias: Vec3,
+    /// Rotation vector error per meter traveled, in the body frame. E.g. heading drift.
+    pub rotation_bias: Vec3,
+    /// The random seed.
+    pub seed: u64,
+}
+
+impl Default for OdometryNoise {
+    fn default() -> Self {
+        OdometryNoise {
+            translation_per_meter: 0.01,
+            rotation_per_meter: 0.001,
+            rotation_per_radian: 0.01,
+            translation_bias: Vec3::ZERO,
+            rotation_bias: Vec3::ZERO,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of corrupting a trajectory with odometry noise.
+pub struct DriftedTrajectory {
+    /// The drifted poses, starting at the first true pose, with the true timestamps.
+    pub poses: Vec<StampedRbt>,
+    /// The error of each relative step, `log(Δtrue⁻¹ * Δdrifted)`.
+    pub step_errors: Vec<Twist>,
+}
+
+impl OdometryNoise {
+    /// Integrates the relative steps of `truth`, each corrupted with noise.
+    pub fn apply(&self, truth: &[StampedRbt]) -> DriftedTrajectory {
+        let mut rng = Rng::new(self.seed);
+        let mut poses = Vec::with_capacity(truth.len());
+        let mut step_errors = Vec::with_capacity(truth.len().saturating_sub(1));
+        let Some(first) = truth.first() else {
+            return DriftedTrajectory { poses, step_errors };
+        };
+        poses.push(first.clone());
+        let mut pose = first.rbt.clone();
+        for pair in truth.windows(2) {
+            let step = pair[0].rbt.inverse() * &pair[1].rbt;
+            let distance = step.translation.length();
+            let angle = so3_log(step.rotation).length();
+            let rotation_sigma = math::sqrt(
+                self.rotation_per_meter * self.rotation_per_meter * distance
+                    + self.rotation_per_radian * self.rotation_per_radian * angle,
+            );
+            let error = Twist::new(
+                self.translation_bias * distance
+                    + rng.gaussian_vec3() * (self.translation_per_meter * math::sqrt(distance)),
+                self.rotation_bias * distance + rng.gaussian_vec3() * rotation_sigma,
+            );
+            pose = pose * step * Rbt::exp(&error);
+            poses.push(StampedRbt::new(pair[1].time, pose.clone()));
+            step_errors.push(error);
+        }
+        DriftedTrajectory { poses, step_errors }
+    }
+}
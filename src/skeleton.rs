@@ -0,0 +1,245 @@
+//! Skeletons and animation retargeting.
+
+use crate::{hierarchy, Mat3, Mat4, Quat, Rbt, Vec3, Vec4};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A joint hierarchy with a rest pose.
+///
+/// Joints are sorted so every parent comes before its children.
+pub struct Skeleton {
+    /// The parent of each joint, `None` for roots.
+    pub parents: Vec<Option<usize>>,
+    /// The local transform of each joint relative to its parent in the rest pose.
+    pub rest: Vec<Rbt>,
+}
+
+impl Skeleton {
+    /// Creates a skeleton.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ or a parent doesn't come before its child.
+    pub fn new(parents: Vec<Option<usize>>, rest: Vec<Rbt>) -> Self {
+        assert_eq!(parents.len(), rest.len(), "length mismatch");
+        for (i, parent) in parents.iter().enumerate() {
+            assert!(parent.is_none_or(|p| p < i), "parent after child");
+        }
+        Skeleton { parents, rest }
+    }
+
+    /// Returns the number of joints.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns whether there are no joints.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the model space transforms of local transforms `locals`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locals` length is not `len()`.
+    pub fn globals(&self, locals: &[Rbt]) -> Vec<Rbt> {
+        let mut globals = vec![Rbt::new(); locals.len()];
+        hierarchy::propagate(&self.parents, locals, &mut globals);
+        globals
+    }
+}
+
+/// Returns the skinning transform `global * inverse_bind` of one joint as rotation and translation.
+fn skinning_transform(global: &Rbt, inverse_bind: &Rbt) -> (Mat3, Vec3) {
+    (
+        Mat3::from_quat(global.rotation * inverse_bind.rotation),
+        global.rotation * inverse_bind.translation + global.translation,
+    )
+}
+
+/// Writes the skinning matrices `globals[i] * inverse_bind[i]` to `out`.
+///
+/// # Panics
+///
+/// Panics if the lengths differ.
+pub fn compute_skinning_palette(globals: &[Rbt], inverse_bind: &[Rbt], out: &mut [Mat4]) {
+    assert!(
+        globals.len() == inverse_bind.len() && globals.len() == out.len(),
+        "length mismatch"
+    );
+    for ((global, inverse_bind), out) in globals.iter().zip(inverse_bind).zip(out) {
+        let (r, t) = skinning_transform(global, inverse_bind);
+        *out = Mat4::from_cols(
+            r.x_axis.extend(0.0),
+            r.y_axis.extend(0.0),
+            r.z_axis.extend(0.0),
+            t.extend(1.0),
+        );
+    }
+}
+
+/// Writes the skinning matrices `globals[i] * inverse_bind[i]` to `out` as the top 3 rows of the 4x4 matrices,
+/// the compact layout for GPU buffers.
+///
+/// # Panics
+///
+/// Panics if the lengths differ.
+pub fn compute_skinning_palette_3x4(globals: &[Rbt], inverse_bind: &[Rbt], out: &mut [[Vec4; 3]]) {
+    assert!(
+        globals.len() == inverse_bind.len() && globals.len() == out.len(),
+        "length mismatch"
+    );
+    for ((global, inverse_bind), out) in globals.iter().zip(inverse_bind).zip(out) {
+        let (r, t) = skinning_transform(global, inverse_bind);
+        *out = [
+            r.row(0).extend(t.x),
+            r.row(1).extend(t.y),
+            r.row(2).extend(t.z),
+        ];
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Local joint transforms of a skeleton, e.g. one animation frame.
+pub struct Pose {
+    /// The local transform of each joint.
+    pub locals: Vec<Rbt>,
+}
+
+impl Pose {
+    /// Creates a pose from local transforms.
+    pub fn new(locals: Vec<Rbt>) -> Self {
+        Pose { locals }
+    }
+
+    /// Blends from `a` at weight 0 to `b` at weight 1, joint by joint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the poses differ in length.
+    pub fn blend(a: &Pose, b: &Pose, weight: f32) -> Pose {
+        assert_eq!(a.locals.len(), b.locals.len(), "length mismatch");
+        Pose::new(
+            a.locals
+                .iter()
+                .zip(&b.locals)
+                .map(|(a, b)| a.lerp(b, weight))
+                .collect(),
+        )
+    }
+
+    /// Blends like `blend`, with the weight of joint `i` scaled by `mask[i]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the poses or the mask differ in length.
+    pub fn blend_masked(a: &Pose, b: &Pose, weight: f32, mask: &[f32]) -> Pose {
+        assert!(
+            a.locals.len() == b.locals.len() && a.locals.len() == mask.len(),
+            "length mismatch"
+        );
+        Pose::new(
+            a.locals
+                .iter()
+                .zip(&b.locals)
+                .zip(mask)
+                .map(|((a, b), m)| a.lerp(b, weight * m))
+                .collect(),
+        )
+    }
+
+    /// Returns the additive delta taking `reference` to `pose`, `reference⁻¹ * pose` for each joint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the poses differ in length.
+    pub fn difference(pose: &Pose, reference: &Pose) -> Pose {
+        assert_eq!(pose.locals.len(), reference.locals.len(), "length mismatch");
+        Pose::new(
+            pose.locals
+                .iter()
+                .zip(&reference.locals)
+                .map(|(p, r)| r.inverse() * p)
+                .collect(),
+        )
+    }
+
+    /// Layers `delta`, as returned by `difference`, on top of `base` with `weight`, optionally scaled per joint by `mask`.
+    ///
+    /// Each joint becomes `base * delta^weight`, with the fractional delta interpolated from identity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the poses or the mask differ in length.
+    pub fn additive(base: &Pose, delta: &Pose, weight: f32, mask: Option<&[f32]>) -> Pose {
+        assert_eq!(base.locals.len(), delta.locals.len(), "length mismatch");
+        if let Some(mask) = mask {
+            assert_eq!(base.locals.len(), mask.len(), "length mismatch");
+        }
+        let identity = Rbt::new();
+        Pose::new(
+            base.locals
+                .iter()
+                .zip(&delta.locals)
+                .enumerate()
+                .map(|(i, (b, d))| {
+                    let w = weight * mask.map_or(1.0, |m| m[i]);
+                    b * identity.lerp(d, w)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Maps animation between skeletons with different proportions and rest orientations.
+///
+/// Each mapped target joint gets the model space rotation change of its source joint from its rest pose,
+/// so differing joint axes don't matter. Target bone lengths are kept; only root translations are carried over.
+pub struct Retargeter {
+    /// The source skeleton.
+    pub source: Skeleton,
+    /// The target skeleton.
+    pub target: Skeleton,
+    /// The source joint of each target joint. Unmapped joints keep their rest local rotation.
+    pub mapping: Vec<Option<usize>>,
+    /// The factor applied to root translation changes, e.g. the ratio of leg lengths.
+    pub translation_scale: f32,
+}
+
+impl Retargeter {
+    /// Maps source local transforms `locals` to target local transforms.
+    pub fn retarget(&self, locals: &[Rbt]) -> Vec<Rbt> {
+        let source_rest = self.source.globals(&self.source.rest);
+        let source_pose = self.source.globals(locals);
+        let target_rest = self.target.globals(&self.target.rest);
+        let mut rotations: Vec<Quat> = Vec::with_capacity(self.target.len());
+        let mut result = Vec::with_capacity(self.target.len());
+        for (i, parent) in self.target.parents.iter().enumerate() {
+            let parent_rotation = parent.map_or(Quat::IDENTITY, |p| rotations[p]);
+            let source = self.mapping.get(i).copied().flatten();
+            let (rotation, local_rotation) = match source {
+                Some(s) => {
+                    let delta = source_pose[s].rotation * source_rest[s].rotation.conjugate();
+                    let rotation = (delta * target_rest[i].rotation).normalize();
+                    (
+                        rotation,
+                        (parent_rotation.conjugate() * rotation).normalize(),
+                    )
+                }
+                None => (
+                    (parent_rotation * self.target.rest[i].rotation).normalize(),
+                    self.target.rest[i].rotation,
+                ),
+            };
+            let mut translation = self.target.rest[i].translation;
+            if let (None, Some(s)) = (parent, source) {
+                translation += (source_pose[s].translation - source_rest[s].translation)
+                    * self.translation_scale;
+            }
+            rotations.push(rotation);
+            result.push(Rbt::from_t_r(translation, local_rotation));
+        }
+        result
+    }
+}
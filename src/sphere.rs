@@ -0,0 +1,102 @@
+//! Bounding spheres.
+
+use crate::{Rbt, Vec3};
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A sphere.
+pub struct Sphere {
+    /// The center.
+    pub center: Vec3,
+    /// The radius.
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Creates a sphere from center and radius.
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Sphere { center, radius }
+    }
+
+    /// Returns whether `point` is inside or on the boundary.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Returns whether `self` and `other` overlap.
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= r * r
+    }
+
+    /// Returns the smallest sphere containing both `self` and `other`.
+    pub fn merge(&self, other: &Sphere) -> Sphere {
+        let d = self.center.distance(other.center);
+        if d + other.radius <= self.radius {
+            return self.clone();
+        }
+        if d + self.radius <= other.radius {
+            return other.clone();
+        }
+        let radius = (d + self.radius + other.radius) * 0.5;
+        Sphere {
+            center: self.center + (other.center - self.center) * ((radius - self.radius) / d),
+            radius,
+        }
+    }
+
+    /// Returns a sphere containing all `spheres`, merging them in order.
+    ///
+    /// Returns `None` if `spheres` is empty. The result is not necessarily the smallest.
+    pub fn merge_all<'a>(spheres: impl IntoIterator<Item = &'a Sphere>) -> Option<Sphere> {
+        let mut iter = spheres.into_iter();
+        let first = iter.next()?.clone();
+        Some(iter.fold(first, |acc, s| acc.merge(s)))
+    }
+
+    /// Returns a sphere containing all `children`, each transformed by its pose.
+    ///
+    /// Useful for bounding a hierarchy from its children's local spheres.
+    pub fn merge_transformed<'a>(
+        children: impl IntoIterator<Item = (&'a Rbt, &'a Sphere)>,
+    ) -> Option<Sphere> {
+        let mut iter = children.into_iter().map(|(rbt, sphere)| rbt * sphere);
+        let first = iter.next()?;
+        Some(iter.fold(first, |acc, s| acc.merge(&s)))
+    }
+}
+
+impl Mul<&Sphere> for &Rbt {
+    type Output = Sphere;
+
+    fn mul(self, rhs: &Sphere) -> Sphere {
+        Sphere {
+            center: self.rotation * rhs.center + self.translation,
+            radius: rhs.radius,
+        }
+    }
+}
+
+impl Mul<Sphere> for &Rbt {
+    type Output = Sphere;
+
+    fn mul(self, rhs: Sphere) -> Sphere {
+        self * &rhs
+    }
+}
+
+impl Mul<&Sphere> for Rbt {
+    type Output = Sphere;
+
+    fn mul(self, rhs: &Sphere) -> Sphere {
+        &self * rhs
+    }
+}
+
+impl Mul<Sphere> for Rbt {
+    type Output = Sphere;
+
+    fn mul(self, rhs: Sphere) -> Sphere {
+        &self * &rhs
+    }
+}
@@ -0,0 +1,19 @@
+//! Timestamped poses.
+
+use crate::Rbt;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A pose with a timestamp.
+pub struct StampedRbt {
+    /// The time in seconds.
+    pub time: f64,
+    /// The pose.
+    pub rbt: Rbt,
+}
+
+impl StampedRbt {
+    /// Creates a stamped pose.
+    pub fn new(time: f64, rbt: Rbt) -> Self {
+        StampedRbt { time, rbt }
+    }
+}
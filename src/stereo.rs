@@ -0,0 +1,62 @@
+//! Stereo camera rigs.
+//!
+//! Cameras use computer vision axes: +X right, +Y down, +Z forward.
+
+use crate::{Mat3, Quat, Rbt, Vec3};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A pair of cameras, given by their camera-to-world poses.
+pub struct StereoRig {
+    /// The pose of the left camera.
+    pub left: Rbt,
+    /// The pose of the right camera.
+    pub right: Rbt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Rotations making the epipolar lines of a rig horizontal and aligned.
+pub struct Rectification {
+    /// Maps left camera coordinates to rectified left camera coordinates, like `R1` of `cv::stereoRectify`.
+    pub left: Quat,
+    /// Maps right camera coordinates to rectified right camera coordinates, like `R2` of `cv::stereoRectify`.
+    pub right: Quat,
+    /// The world orientation shared by both rectified cameras.
+    pub orientation: Quat,
+}
+
+impl StereoRig {
+    /// Creates a rig from camera-to-world poses.
+    pub fn new(left: Rbt, right: Rbt) -> Self {
+        StereoRig { left, right }
+    }
+
+    /// Returns the transform from left camera coordinates to right camera coordinates, like `R` and `T` of `cv::stereoCalibrate`.
+    pub fn relative(&self) -> Rbt {
+        self.right.inverse() * &self.left
+    }
+
+    /// Returns the distance between the camera centers.
+    pub fn baseline(&self) -> f32 {
+        self.left.translation.distance(self.right.translation)
+    }
+
+    /// Returns the rectifying rotations.
+    ///
+    /// The rectified X axis runs along the baseline from left to right, and the rectified forward axis
+    /// is as close as possible to the mean forward axis of both cameras.
+    ///
+    /// Returns `None` if the camera centers coincide, or the mean forward axis is zero or parallel to the baseline,
+    /// which leaves the rectified forward axis undefined.
+    pub fn rectification(&self) -> Option<Rectification> {
+        let x = (self.right.translation - self.left.translation).try_normalize()?;
+        let forward = self.left.rotation * Vec3::Z + self.right.rotation * Vec3::Z;
+        let y = forward.cross(x).try_normalize()?;
+        let z = x.cross(y);
+        let orientation = Quat::from_mat3(&Mat3::from_cols(x, y, z)).normalize();
+        Some(Rectification {
+            left: (orientation.conjugate() * self.left.rotation).normalize(),
+            right: (orientation.conjugate() * self.right.rotation).normalize(),
+            orientation,
+        })
+    }
+}
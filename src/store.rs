@@ -0,0 +1,258 @@
+//! Flat transform storage with incremental updates.
+
+use crate::Rbt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A handle to a transform in a `TransformStore`.
+///
+/// Stays invalid once its transform is removed, even if the slot is reused.
+pub struct TransformHandle {
+    index: usize,
+    generation: u32,
+}
+
+impl TransformHandle {
+    /// Returns the slot index, which may be reused after removal.
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// A transform hierarchy in structure-of-arrays layout.
+///
+/// Changes mark subtrees dirty, and `update` recomputes the globals of only those.
+/// Each transform records the update at which its global last changed, for change detection.
+pub struct TransformStore {
+    locals: Vec<Rbt>,
+    globals: Vec<Rbt>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    dirty: Vec<bool>,
+    versions: Vec<u64>,
+    dirty_list: Vec<usize>,
+    free: Vec<usize>,
+    version: u64,
+    len: usize,
+}
+
+impl TransformStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the number of transforms.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no transforms.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of completed `update`s.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns whether `handle` refers to a transform in the store.
+    pub fn contains(&self, handle: TransformHandle) -> bool {
+        self.generations.get(handle.index) == Some(&handle.generation) && self.alive[handle.index]
+    }
+
+    fn slot(&self, handle: TransformHandle) -> Option<usize> {
+        self.contains(handle).then_some(handle.index)
+    }
+
+    /// Adds a transform with local transform `local` under `parent`, or as a root.
+    ///
+    /// Its global is computed by the next `update`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is invalid.
+    pub fn insert(&mut self, parent: Option<TransformHandle>, local: Rbt) -> TransformHandle {
+        let parent = parent.map(|p| self.slot(p).expect("invalid parent handle"));
+        let index = match self.free.pop() {
+            Some(i) => {
+                self.locals[i] = local;
+                self.globals[i] = Rbt::new();
+                self.parents[i] = parent;
+                self.alive[i] = true;
+                self.versions[i] = 0;
+                i
+            }
+            None => {
+                self.locals.push(local);
+                self.globals.push(Rbt::new());
+                self.parents.push(parent);
+                self.children.push(Vec::new());
+                self.generations.push(0);
+                self.alive.push(true);
+                self.dirty.push(false);
+                self.versions.push(0);
+                self.locals.len() - 1
+            }
+        };
+        if let Some(p) = parent {
+            self.children[p].push(index);
+        }
+        self.len += 1;
+        self.mark_dirty(index);
+        TransformHandle {
+            index,
+            generation: self.generations[index],
+        }
+    }
+
+    /// Removes the transform of `handle` and all its descendants. Returns whether it was in the store.
+    pub fn remove(&mut self, handle: TransformHandle) -> bool {
+        let Some(index) = self.slot(handle) else {
+            return false;
+        };
+        if let Some(p) = self.parents[index] {
+            self.children[p].retain(|&c| c != index);
+        }
+        let mut stack = vec![index];
+        while let Some(i) = stack.pop() {
+            stack.append(&mut self.children[i]);
+            self.alive[i] = false;
+            self.dirty[i] = false;
+            self.parents[i] = None;
+            self.generations[i] = self.generations[i].wrapping_add(1);
+            self.free.push(i);
+            self.len -= 1;
+        }
+        true
+    }
+
+    /// Returns the local transform of `handle`.
+    pub fn local(&self, handle: TransformHandle) -> Option<&Rbt> {
+        self.slot(handle).map(|i| &self.locals[i])
+    }
+
+    /// Sets the local transform of `handle`. Returns whether it was in the store.
+    pub fn set_local(&mut self, handle: TransformHandle, local: Rbt) -> bool {
+        let Some(index) = self.slot(handle) else {
+            return false;
+        };
+        self.locals[index] = local;
+        self.mark_dirty(index);
+        true
+    }
+
+    /// Returns the global transform of `handle` as of the last `update`.
+    pub fn global(&self, handle: TransformHandle) -> Option<&Rbt> {
+        self.slot(handle).map(|i| &self.globals[i])
+    }
+
+    /// Returns the `version` of the update that last changed the global transform of `handle`.
+    pub fn changed_at(&self, handle: TransformHandle) -> Option<u64> {
+        self.slot(handle).map(|i| self.versions[i])
+    }
+
+    /// Returns the parent of `handle`, `None` for roots and invalid handles.
+    pub fn parent(&self, handle: TransformHandle) -> Option<TransformHandle> {
+        let p = self.parents[self.slot(handle)?]?;
+        Some(TransformHandle {
+            index: p,
+            generation: self.generations[p],
+        })
+    }
+
+    /// Moves `child` under `parent`, or makes it a root, keeping its local transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handle is invalid, or `parent` is `child` or one of its descendants.
+    pub fn set_parent(&mut self, child: TransformHandle, parent: Option<TransformHandle>) {
+        let index = self.slot(child).expect("invalid child handle");
+        let parent = parent.map(|p| self.slot(p).expect("invalid parent handle"));
+        let mut ancestor = parent;
+        while let Some(a) = ancestor {
+            assert_ne!(a, index, "cycle in transform hierarchy");
+            ancestor = self.parents[a];
+        }
+        if let Some(p) = self.parents[index] {
+            self.children[p].retain(|&c| c != index);
+        }
+        if let Some(p) = parent {
+            self.children[p].push(index);
+        }
+        self.parents[index] = parent;
+        self.mark_dirty(index);
+    }
+
+    fn mark_dirty(&mut self, index: usize) {
+        if !self.dirty[index] {
+            self.dirty[index] = true;
+            self.dirty_list.push(index);
+        }
+    }
+
+    /// Recomputes the global transforms of the dirty subtrees. Returns the number of transforms recomputed.
+    pub fn update(&mut self) -> usize {
+        self.version += 1;
+        let mut count = 0;
+        let mut stack = Vec::new();
+        let dirty_list = std::mem::take(&mut self.dirty_list);
+        for &root in &dirty_list {
+            // Subtrees under a dirty ancestor are recomputed with it.
+            let mut ancestor = self.parents[root];
+            let covered = loop {
+                match ancestor {
+                    Some(a) if self.dirty[a] => break true,
+                    Some(a) => ancestor = self.parents[a],
+                    None => break false,
+                }
+            };
+            if covered || !self.dirty[root] || self.versions[root] == self.version {
+                continue;
+            }
+            stack.push(root);
+            while let Some(i) = stack.pop() {
+                self.globals[i] = match self.parents[i] {
+                    Some(p) => &self.globals[p] * &self.locals[i],
+                    None => self.locals[i].clone(),
+                };
+                self.versions[i] = self.version;
+                stack.extend_from_slice(&self.children[i]);
+                count += 1;
+            }
+        }
+        // Cleared last so the ancestor check above sees every dirty mark.
+        for i in dirty_list {
+            self.dirty[i] = false;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3;
+
+    #[test]
+    fn reused_slot_starts_fresh() {
+        let mut store = TransformStore::new();
+        let old = store.insert(None, Rbt::from_t(Vec3::X));
+        let _ = store.update();
+        let _ = store.update();
+        assert!(store.remove(old));
+
+        let new = store.insert(None, Rbt::from_t(Vec3::Y));
+        assert_eq!(new.index(), old.index());
+        assert_eq!(store.global(new), Some(&Rbt::new()));
+        assert_eq!(store.changed_at(new), Some(0));
+
+        let _ = store.update();
+        assert_eq!(store.global(new), Some(&Rbt::from_t(Vec3::Y)));
+        assert_eq!(store.changed_at(new), Some(store.version()));
+        assert_eq!(store.global(old), None);
+    }
+}
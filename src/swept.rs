@@ -0,0 +1,92 @@
+//! Bounds swept along screw motions.
+
+use crate::{math, Aabb, Rbt, Sphere, Twist, Vec3};
+
+/// The largest rotation between consecutive samples of a swept bound.
+const MAX_SEGMENT_ANGLE: f32 = std::f32::consts::PI / 8.0;
+
+/// The screw motion of `Rbt::screw_interpolate`, split into segments of bounded rotation.
+struct Screw {
+    from: Rbt,
+    twist: Twist,
+    segments: usize,
+    axis_point: Vec3,
+    axis_direction: Vec3,
+    /// The largest distance of an arc of unit radius from its chord.
+    sagitta: f32,
+}
+
+impl Screw {
+    fn new(from: &Rbt, to: &Rbt) -> Self {
+        let twist = (from.inverse() * to).log();
+        let theta = twist.angular.length();
+        let segments = ((theta / MAX_SEGMENT_ANGLE).ceil() as usize).max(1);
+        let (axis_point, axis_direction) = if theta > 1e-6 {
+            (
+                twist.angular.cross(twist.linear) / (theta * theta),
+                twist.angular / theta,
+            )
+        } else {
+            (Vec3::ZERO, Vec3::ZERO)
+        };
+        Screw {
+            from: from.clone(),
+            twist,
+            segments,
+            axis_point,
+            axis_direction,
+            sagitta: 1.0 - math::cos(theta / segments as f32 * 0.5),
+        }
+    }
+
+    /// Returns the poses at the segment ends, including both ends of the motion.
+    fn poses(&self) -> impl Iterator<Item = Rbt> + '_ {
+        (0..=self.segments)
+            .map(|i| &self.from * Rbt::exp(&(self.twist * (i as f32 / self.segments as f32))))
+    }
+
+    /// Returns how far the path of local `point` strays from the chords between the segment ends.
+    fn padding(&self, point: Vec3) -> f32 {
+        let d = point - self.axis_point;
+        (d - self.axis_direction * d.dot(self.axis_direction)).length() * self.sagitta
+    }
+}
+
+impl Aabb {
+    /// Returns an AABB containing `self` moving from pose `from` to `to` along the screw path.
+    ///
+    /// Conservative, for continuous collision broad phases. The path is that of `Rbt::screw_interpolate`.
+    pub fn swept(&self, from: &Rbt, to: &Rbt) -> Aabb {
+        let screw = Screw::new(from, to);
+        let (min, max) = (self.min, self.max);
+        let padding = [min, max]
+            .into_iter()
+            .flat_map(|x| [min, max].map(|y| (x.x, y.y)))
+            .flat_map(|(x, y)| [min.z, max.z].map(|z| Vec3::new(x, y, z)))
+            .fold(0.0f32, |m, corner| m.max(screw.padding(corner)));
+        let bounds = screw
+            .poses()
+            .map(|pose| pose.transform_aabb(self))
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default();
+        Aabb::new(
+            bounds.min - Vec3::splat(padding),
+            bounds.max + Vec3::splat(padding),
+        )
+    }
+}
+
+impl Sphere {
+    /// Returns a sphere containing `self` moving from pose `from` to `to` along the screw path.
+    ///
+    /// Conservative, but not necessarily the smallest. The path is that of `Rbt::screw_interpolate`.
+    pub fn swept(&self, from: &Rbt, to: &Rbt) -> Sphere {
+        let screw = Screw::new(from, to);
+        let radius = self.radius + screw.padding(self.center);
+        screw
+            .poses()
+            .map(|pose| Sphere::new((&pose * self).center, radius))
+            .reduce(|a, b| a.merge(&b))
+            .unwrap_or_default()
+    }
+}
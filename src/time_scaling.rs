@@ -0,0 +1,184 @@
+//! Time-parameterized motions through poses.
+
+use crate::{Rbt, StampedRbt, Twist};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Velocity and acceleration limits of a motion.
+///
+/// Linear limits apply to the speed of the body origin, angular limits to the rotation rate.
+pub struct MotionLimits {
+    /// The maximum linear speed.
+    pub max_linear_velocity: f32,
+    /// The maximum angular speed, in radians per second.
+    pub max_angular_velocity: f32,
+    /// The maximum linear acceleration.
+    pub max_linear_acceleration: f32,
+    /// The maximum angular acceleration, in radians per second squared.
+    pub max_angular_acceleration: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A trapezoidal velocity profile moving a progress value from 0 to 1 from rest to rest.
+pub struct Trapezoid {
+    /// The total duration.
+    pub duration: f64,
+    /// The duration of each of the acceleration and deceleration phases.
+    pub accel_time: f64,
+    /// The progress rate while cruising.
+    pub peak_rate: f64,
+}
+
+impl Trapezoid {
+    /// Creates the fastest profile with progress rate at most `max_rate` and its derivative at most `max_accel`.
+    ///
+    /// The profile is triangular if `max_rate` can't be reached.
+    pub fn new(max_rate: f64, max_accel: f64) -> Self {
+        if max_rate * max_rate >= max_accel {
+            let accel_time = (1.0 / max_accel).sqrt();
+            Trapezoid {
+                duration: 2.0 * accel_time,
+                accel_time,
+                peak_rate: max_accel * accel_time,
+            }
+        } else {
+            let accel_time = max_rate / max_accel;
+            Trapezoid {
+                duration: 2.0 * accel_time + (1.0 - max_rate * accel_time) / max_rate,
+                accel_time,
+                peak_rate: max_rate,
+            }
+        }
+    }
+
+    /// Returns the progress at `time`, clamped to `[0, 1]`.
+    pub fn progress(&self, time: f64) -> f64 {
+        if time <= 0.0 || self.duration <= 0.0 {
+            return if self.duration <= 0.0 { 1.0 } else { 0.0 };
+        }
+        if time >= self.duration {
+            return 1.0;
+        }
+        let accel = self.peak_rate / self.accel_time;
+        let remaining = self.duration - time;
+        if time < self.accel_time {
+            0.5 * accel * time * time
+        } else if remaining < self.accel_time {
+            1.0 - 0.5 * accel * remaining * remaining
+        } else {
+            self.peak_rate * (time - 0.5 * self.accel_time)
+        }
+    }
+
+    /// Returns the progress rate at `time`.
+    pub fn rate(&self, time: f64) -> f64 {
+        if time <= 0.0 || time >= self.duration {
+            return 0.0;
+        }
+        let remaining = self.duration - time;
+        self.peak_rate * (time.min(remaining) / self.accel_time).min(1.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TimedSegment {
+    start: Rbt,
+    twist: Twist,
+    start_time: f64,
+    profile: Trapezoid,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A motion through waypoints along screw segments, stopping at each waypoint.
+pub struct TimedPath {
+    segments: Vec<TimedSegment>,
+    end: Rbt,
+}
+
+impl TimedPath {
+    /// Creates the fastest rest-to-rest motion through `waypoints` within `limits`.
+    pub fn new(waypoints: &[Rbt], limits: &MotionLimits) -> Self {
+        let mut segments = Vec::with_capacity(waypoints.len().saturating_sub(1));
+        let mut time = 0.0;
+        for pair in waypoints.windows(2) {
+            let twist = (pair[0].inverse() * &pair[1]).log();
+            let (linear, angular) = (twist.linear.length() as f64, twist.angular.length() as f64);
+            let ratio = |max: f32, length: f64| {
+                if length > 0.0 {
+                    max as f64 / length
+                } else {
+                    f64::INFINITY
+                }
+            };
+            let max_rate = ratio(limits.max_linear_velocity, linear)
+                .min(ratio(limits.max_angular_velocity, angular));
+            let max_accel = ratio(limits.max_linear_acceleration, linear)
+                .min(ratio(limits.max_angular_acceleration, angular));
+            let profile = if max_rate.is_finite() && max_accel.is_finite() {
+                Trapezoid::new(max_rate, max_accel)
+            } else {
+                Trapezoid::default()
+            };
+            let duration = profile.duration;
+            segments.push(TimedSegment {
+                start: pair[0].clone(),
+                twist,
+                start_time: time,
+                profile,
+            });
+            time += duration;
+        }
+        TimedPath {
+            segments,
+            end: waypoints.last().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Returns the total duration.
+    pub fn duration(&self) -> f64 {
+        self.segments
+            .last()
+            .map_or(0.0, |s| s.start_time + s.profile.duration)
+    }
+
+    /// Returns the pose at `time`, clamped to the path ends.
+    pub fn sample(&self, time: f64) -> Rbt {
+        let i = self.segments.partition_point(|s| s.start_time <= time);
+        let Some(segment) = i.checked_sub(1).map(|i| &self.segments[i]) else {
+            return self
+                .segments
+                .first()
+                .map_or(self.end.clone(), |s| s.start.clone());
+        };
+        let s = segment.profile.progress(time - segment.start_time);
+        if s >= 1.0 && i == self.segments.len() {
+            return self.end.clone();
+        }
+        &segment.start * Rbt::exp(&(segment.twist * s as f32))
+    }
+
+    /// Returns the body twist at `time`.
+    pub fn velocity(&self, time: f64) -> Twist {
+        let i = self.segments.partition_point(|s| s.start_time <= time);
+        match i.checked_sub(1).map(|i| &self.segments[i]) {
+            Some(segment) => segment.twist * segment.profile.rate(time - segment.start_time) as f32,
+            None => Twist::ZERO,
+        }
+    }
+
+    /// Samples the path every `dt` seconds, including both ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dt` is not positive.
+    pub fn sample_uniform(&self, dt: f64) -> Vec<StampedRbt> {
+        assert!(dt > 0.0, "dt must be positive");
+        let duration = self.duration();
+        let n = (duration / dt).ceil() as usize;
+        (0..=n)
+            .map(|k| {
+                let time = (k as f64 * dt).min(duration);
+                StampedRbt::new(time, self.sample(time))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+//! Keyframed animation tracks.
+
+use crate::lie::so3_log;
+use crate::{Rbt, StampedRbt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How a track interpolates between keys.
+pub enum Interpolation {
+    /// Holds each key until the next one.
+    Step,
+    /// Lerps translation and slerps rotation.
+    #[default]
+    Linear,
+    /// Follows the constant screw motion between keys.
+    Screw,
+}
+
+impl Interpolation {
+    /// Interpolates from `a` at `t = 0` to `b` at `t = 1`.
+    pub fn interpolate(self, a: &Rbt, b: &Rbt, t: f32) -> Rbt {
+        match self {
+            Interpolation::Step if t < 1.0 => a.clone(),
+            Interpolation::Step => b.clone(),
+            Interpolation::Linear => a.lerp(b, t),
+            Interpolation::Screw => a.screw_interpolate(b, t),
+        }
+    }
+
+    /// Returns the translation and rotation errors of `key` from the interpolation of `a` and `b` at its time.
+    pub(crate) fn deviation(self, a: &StampedRbt, b: &StampedRbt, key: &StampedRbt) -> (f32, f32) {
+        let t = ((key.time - a.time) / (b.time - a.time)) as f32;
+        let p = self.interpolate(&a.rbt, &b.rbt, t);
+        (
+            p.translation.distance(key.rbt.translation),
+            so3_log(p.rotation.conjugate() * key.rbt.rotation).length(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A sequence of keyframes with an interpolation mode.
+pub struct RbtTrack {
+    /// The keys, sorted by time.
+    pub keys: Vec<StampedRbt>,
+    /// The interpolation between keys.
+    pub interpolation: Interpolation,
+}
+
+impl RbtTrack {
+    /// Creates a track.
+    pub fn new(keys: Vec<StampedRbt>, interpolation: Interpolation) -> Self {
+        RbtTrack {
+            keys,
+            interpolation,
+        }
+    }
+
+    /// Returns the pose at `time`, clamped to the first and last keys, or `None` if there are no keys.
+    pub fn sample(&self, time: f64) -> Option<Rbt> {
+        let i = self.keys.partition_point(|k| k.time <= time);
+        match (i.checked_sub(1).map(|i| &self.keys[i]), self.keys.get(i)) {
+            (Some(a), Some(b)) => Some(self.interpolation.interpolate(
+                &a.rbt,
+                &b.rbt,
+                ((time - a.time) / (b.time - a.time)) as f32,
+            )),
+            (Some(k), None) | (None, Some(k)) => Some(k.rbt.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the track with keys removed where interpolating the remaining ones reproduces them.
+    ///
+    /// Every removed key stays within `translation_tolerance` and `rotation_tolerance` radians of the reduced track.
+    /// The first and last keys are always kept.
+    pub fn reduce(&self, translation_tolerance: f32, rotation_tolerance: f32) -> RbtTrack {
+        let keys = &self.keys;
+        if keys.len() <= 2 {
+            return self.clone();
+        }
+        let fits = |a: usize, b: usize| {
+            keys[a + 1..b].iter().all(|k| {
+                let (translation, rotation) = self.interpolation.deviation(&keys[a], &keys[b], k);
+                translation <= translation_tolerance && rotation <= rotation_tolerance
+            })
+        };
+        let mut reduced = vec![keys[0].clone()];
+        let mut anchor = 0;
+        let mut end = 2;
+        while end < keys.len() {
+            if !fits(anchor, end) {
+                anchor = end - 1;
+                reduced.push(keys[anchor].clone());
+            }
+            end += 1;
+        }
+        reduced.push(keys[keys.len() - 1].clone());
+        RbtTrack::new(reduced, self.interpolation)
+    }
+}
@@ -0,0 +1,310 @@
+//! Timestamped pose sequences.
+
+use crate::lie::{mat6_mul_twist, Mat6};
+pub use crate::stamped::StampedRbt;
+use crate::track::Interpolation;
+use crate::uncertainty::transport;
+use crate::{linalg, Rbt, Twist};
+
+/// Replaces every pose `T` with `lhs * T`, e.g. to re-express a trajectory in a new reference frame.
+pub fn left_multiply(poses: &mut [StampedRbt], lhs: &Rbt) {
+    for pose in poses {
+        pose.rbt = lhs * &pose.rbt;
+    }
+}
+
+/// Replaces every pose `T` with `T * rhs`, e.g. to track a different point on the moving body.
+pub fn right_multiply(poses: &mut [StampedRbt], rhs: &Rbt) {
+    for pose in poses {
+        pose.rbt = &pose.rbt * rhs;
+    }
+}
+
+/// Appends `b` to `a`, moved so its first pose coincides with the last pose of `a`.
+///
+/// Times of `b` are shifted the same way. The first pose of `b` is dropped as it duplicates the seam.
+/// Returns `b` unchanged if `a` is empty.
+pub fn concatenate(a: &[StampedRbt], b: &[StampedRbt]) -> Vec<StampedRbt> {
+    let mut result = a.to_vec();
+    let (Some(last), Some(first)) = (a.last(), b.first()) else {
+        result.extend_from_slice(b);
+        return result;
+    };
+    let offset = &last.rbt * first.rbt.inverse();
+    result.extend(b[1..].iter().map(|p| StampedRbt {
+        time: last.time + (p.time - first.time),
+        rbt: &offset * &p.rbt,
+    }));
+    result
+}
+
+/// Returns the relative poses `T[i]⁻¹ * T[i + step]`, stamped with the later time.
+///
+/// # Panics
+///
+/// Panics if `step` is 0.
+pub fn relative_poses(poses: &[StampedRbt], step: usize) -> Vec<StampedRbt> {
+    assert!(step > 0, "step must be positive");
+    poses
+        .iter()
+        .zip(poses.iter().skip(step))
+        .map(|(a, b)| StampedRbt {
+            time: b.time,
+            rbt: a.rbt.inverse() * &b.rbt,
+        })
+        .collect()
+}
+
+/// Simplifies `poses` by Douglas–Peucker, keeping a subset that reproduces every pose under `interpolation`
+/// within `translation_tolerance` and `rotation_tolerance` radians.
+///
+/// Poses must be sorted by time. The first and last poses are always kept.
+pub fn simplify(
+    poses: &[StampedRbt],
+    translation_tolerance: f32,
+    rotation_tolerance: f32,
+    interpolation: Interpolation,
+) -> Vec<StampedRbt> {
+    if poses.len() <= 2 {
+        return poses.to_vec();
+    }
+    let mut keep = vec![false; poses.len()];
+    keep[0] = true;
+    keep[poses.len() - 1] = true;
+    let mut stack = vec![(0, poses.len() - 1)];
+    while let Some((a, b)) = stack.pop() {
+        // The worst pose relative to the tolerances, if any exceeds them.
+        let worst = (a + 1..b)
+            .map(|i| {
+                let (t, r) = interpolation.deviation(&poses[a], &poses[b], &poses[i]);
+                (i, (t / translation_tolerance).max(r / rotation_tolerance))
+            })
+            .filter(|&(_, e)| e > 1.0)
+            .max_by(|x, y| x.1.total_cmp(&y.1));
+        if let Some((i, _)) = worst {
+            keep[i] = true;
+            stack.push((a, i));
+            stack.push((i, b));
+        }
+    }
+    poses
+        .iter()
+        .zip(keep)
+        .filter(|&(_, k)| k)
+        .map(|(p, _)| p.clone())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// How `distribute_loop_closure` spreads the correction over the steps of a trajectory.
+pub enum DriftWeighting<'a> {
+    /// Equally per step.
+    Uniform,
+    /// In proportion to the distance travelled.
+    Distance,
+    /// In proportion to the uncertainty of each step, given as the covariance of the right perturbation of
+    /// `T[i]⁻¹ * T[i + 1]`. The correction is then the smallest in the Mahalanobis sense, to first order.
+    Covariance(&'a [Mat6]),
+}
+
+/// Bends `poses` so the last one becomes `corrected_end`, e.g. after a loop closure, keeping the first pose fixed.
+///
+/// Each pose `T[i]` becomes `exp(c[i]) * T[i]`, where the world frame corrections `c[i]` grow from zero to
+/// `log(corrected_end * T[n]⁻¹)` as weighted by `weighting`.
+///
+/// # Panics
+///
+/// Panics if `weighting` has covariances and their number is not one less than the number of poses.
+pub fn distribute_loop_closure(
+    poses: &[StampedRbt],
+    corrected_end: &Rbt,
+    weighting: DriftWeighting,
+) -> Vec<StampedRbt> {
+    let Some(last) = poses.last() else {
+        return Vec::new();
+    };
+    let error = (corrected_end * last.rbt.inverse()).log();
+    let n = poses.len() - 1;
+    let uniform = |i: usize| error * (i as f32 / n.max(1) as f32);
+    let corrections: Vec<Twist> = match weighting {
+        DriftWeighting::Uniform => (0..=n).map(uniform).collect(),
+        DriftWeighting::Distance => {
+            let mut travelled = vec![0.0f32; n + 1];
+            for i in 0..n {
+                let step = poses[i]
+                    .rbt
+                    .translation
+                    .distance(poses[i + 1].rbt.translation);
+                travelled[i + 1] = travelled[i] + step;
+            }
+            if travelled[n] > 0.0 {
+                travelled
+                    .iter()
+                    .map(|d| error * (d / travelled[n]))
+                    .collect()
+            } else {
+                (0..=n).map(uniform).collect()
+            }
+        }
+        DriftWeighting::Covariance(covariances) => {
+            assert_eq!(covariances.len(), n, "need one covariance per step");
+            // A perturbation of step i moves every later pose by it, transported to the world frame.
+            let mut cumulative = vec![[[0.0f64; 6]; 6]; n + 1];
+            for i in 0..n {
+                let world = transport(&covariances[i], &poses[i + 1].rbt);
+                cumulative[i + 1] = cumulative[i];
+                for (row, w) in cumulative[i + 1].iter_mut().zip(world) {
+                    row.iter_mut().zip(w).for_each(|(x, w)| *x += w as f64);
+                }
+            }
+            match linalg::solve_spd(&cumulative[n], &error.to_array().map(|x| x as f64)) {
+                Some(x) => {
+                    let x = Twist::from_array(x.map(|x| x as f32));
+                    cumulative
+                        .iter()
+                        .map(|c| mat6_mul_twist(&c.map(|row| row.map(|x| x as f32)), &x))
+                        .collect()
+                }
+                None => (0..=n).map(uniform).collect(),
+            }
+        }
+    };
+    poses
+        .iter()
+        .zip(corrections)
+        .map(|(p, c)| StampedRbt::new(p.time, Rbt::exp(&c) * &p.rbt))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The frame velocities are expressed in.
+pub enum VelocityFrame {
+    /// The moving body's own frame at each sample.
+    Body,
+    /// The frame the poses are given in. Both parts are body velocities rotated into this frame.
+    World,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Savitzky-Golay style smoothing: a local least squares polynomial fit around each sample.
+///
+/// Works with non-uniform sampling.
+pub struct Smoothing {
+    /// The number of neighbors on each side of a sample used in its fit.
+    pub half_window: usize,
+    /// The polynomial order.
+    pub order: usize,
+}
+
+impl Default for Smoothing {
+    fn default() -> Self {
+        Smoothing {
+            half_window: 3,
+            order: 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Estimated velocities and accelerations, one per sample.
+pub struct MotionProfile {
+    /// The velocity twists.
+    pub velocities: Vec<Twist>,
+    /// The acceleration twists, derivatives of `velocities`.
+    pub accelerations: Vec<Twist>,
+}
+
+/// Estimates velocities and accelerations of a trajectory sorted by time.
+///
+/// Velocities are central differences on SE(3), `log(T[i-1]⁻¹ * T[i+1]) / Δt`,
+/// falling back to one-sided differences at the ends.
+/// Accelerations are central differences of velocities.
+/// If `smoothing` is given, it's applied to both.
+/// Samples with equal times give infinite values.
+pub fn motion_profile(
+    poses: &[StampedRbt],
+    frame: VelocityFrame,
+    smoothing: Option<&Smoothing>,
+) -> MotionProfile {
+    let n = poses.len();
+    if n < 2 {
+        return MotionProfile {
+            velocities: vec![Twist::ZERO; n],
+            accelerations: vec![Twist::ZERO; n],
+        };
+    }
+    let times: Vec<f64> = poses.iter().map(|p| p.time).collect();
+
+    let mut velocities: Vec<Twist> = (0..n)
+        .map(|i| {
+            let (a, b) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            let dt = (times[b] - times[a]) as f32;
+            let body = (poses[a].rbt.inverse() * &poses[b].rbt).log() * (1.0 / dt);
+            match frame {
+                VelocityFrame::Body => body,
+                VelocityFrame::World => {
+                    let r = poses[i].rbt.rotation;
+                    Twist::new(r * body.linear, r * body.angular)
+                }
+            }
+        })
+        .collect();
+    if let Some(smoothing) = smoothing {
+        velocities = smooth(&times, &velocities, smoothing);
+    }
+
+    let mut accelerations: Vec<Twist> = (0..n)
+        .map(|i| {
+            let (a, b) = (i.saturating_sub(1), (i + 1).min(n - 1));
+            let dt = (times[b] - times[a]) as f32;
+            (velocities[b] - velocities[a]) * (1.0 / dt)
+        })
+        .collect();
+    if let Some(smoothing) = smoothing {
+        accelerations = smooth(&times, &accelerations, smoothing);
+    }
+
+    MotionProfile {
+        velocities,
+        accelerations,
+    }
+}
+
+/// Smooths each component of `values` with a local polynomial fit.
+fn smooth(times: &[f64], values: &[Twist], smoothing: &Smoothing) -> Vec<Twist> {
+    let n = values.len();
+    (0..n)
+        .map(|i| {
+            let lo = i.saturating_sub(smoothing.half_window);
+            let hi = (i + smoothing.half_window + 1).min(n);
+            // Can't fit more coefficients than samples.
+            let dim = (smoothing.order + 1).min(hi - lo);
+            let mut a = vec![vec![0.0; dim]; dim];
+            let mut b = vec![[0.0; 6]; dim];
+            for j in lo..hi {
+                let dt = times[j] - times[i];
+                let mut powers = vec![1.0; dim];
+                for k in 1..dim {
+                    powers[k] = powers[k - 1] * dt;
+                }
+                let v = values[j].to_array();
+                for r in 0..dim {
+                    for c in 0..dim {
+                        a[r][c] += powers[r] * powers[c];
+                    }
+                    for (bc, x) in b[r].iter_mut().zip(&v) {
+                        *bc += powers[r] * *x as f64;
+                    }
+                }
+            }
+            let mut out = [0.0; 6];
+            for (c, o) in out.iter_mut().enumerate() {
+                let rhs: Vec<f64> = b.iter().map(|row| row[c]).collect();
+                // The constant coefficient is the smoothed value at `times[i]`.
+                *o = linalg::solve_spd_dyn(&a, &rhs)
+                    .map_or(values[i].to_array()[c], |x| x[0] as f32);
+            }
+            Twist::from_array(out)
+        })
+        .collect()
+}
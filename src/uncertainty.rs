@@ -0,0 +1,211 @@
+//! Poses with uncertainty.
+//!
+//! Covariances are of the right perturbation: the pose is `mean * exp(δ)` with `δ` zero mean.
+
+use crate::lie::{mat6_from_blocks, Mat6};
+use crate::rng::Rng;
+use crate::{linalg, Mat3, Rbt, Twist, Vec3};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A pose with Gaussian uncertainty.
+pub struct UncertainRbt {
+    /// The mean pose.
+    pub mean: Rbt,
+    /// The covariance of the perturbation.
+    pub covariance: Mat6,
+}
+
+impl UncertainRbt {
+    /// Creates an uncertain pose.
+    pub fn new(mean: Rbt, covariance: Mat6) -> Self {
+        UncertainRbt { mean, covariance }
+    }
+
+    /// Fits the mean and sample covariance of `samples`.
+    ///
+    /// The mean is found by iterating on the tangent space around it. Returns `None` if there are fewer than 2 samples.
+    pub fn fit(samples: &[Rbt]) -> Option<UncertainRbt> {
+        if samples.len() < 2 {
+            return None;
+        }
+        let mut mean = samples[0].clone();
+        for _ in 0..20 {
+            let inverse = mean.inverse();
+            let mut sum = [0.0f64; 6];
+            for sample in samples {
+                let delta = (&inverse * sample).log().to_array();
+                sum.iter_mut().zip(delta).for_each(|(s, d)| *s += d as f64);
+            }
+            let step = Twist::from_array(sum.map(|s| (s / samples.len() as f64) as f32));
+            mean = mean * Rbt::exp(&step);
+            if step.length() < 1e-7 {
+                break;
+            }
+        }
+        let inverse = mean.inverse();
+        let mut covariance = [[0.0f64; 6]; 6];
+        for sample in samples {
+            let delta = (&inverse * sample).log().to_array();
+            for (row, a) in covariance.iter_mut().zip(delta) {
+                for (x, b) in row.iter_mut().zip(delta) {
+                    *x += a as f64 * b as f64;
+                }
+            }
+        }
+        let n = (samples.len() - 1) as f64;
+        Some(UncertainRbt {
+            mean,
+            covariance: covariance.map(|row| row.map(|x| (x / n) as f32)),
+        })
+    }
+
+    /// Returns the uncertain `self * rhs` to first order, for independent `self` and `rhs`.
+    pub fn compound_first_order(&self, rhs: &UncertainRbt) -> UncertainRbt {
+        let transported = transport(&self.covariance, &rhs.mean.inverse());
+        UncertainRbt {
+            mean: &self.mean * &rhs.mean,
+            covariance: add(&transported, &rhs.covariance),
+        }
+    }
+
+    /// Returns the uncertain `self * rhs` to fourth order in the perturbations, for independent `self` and `rhs`.
+    ///
+    /// Follows Barfoot and Furgale, "Associating Uncertainty With Three-Dimensional Poses for Use in Estimation Problems".
+    /// The extra terms matter for large rotational uncertainty, where first order is overconfident.
+    pub fn compound(&self, rhs: &UncertainRbt) -> UncertainRbt {
+        // A right perturbation of `self * rhs` is a left perturbation of `rhs⁻¹ * self⁻¹`,
+        // which is the form of the paper with its first pose `rhs⁻¹` and second pose `self⁻¹`.
+        let s1 = rhs.covariance;
+        let s2 = transport(&self.covariance, &rhs.mean.inverse());
+        let (a1, a2) = (quadratic(&s1), quadratic(&s2));
+        let (tt1, tr1, rr1) = blocks(&s1);
+        let (tt2, tr2, rr2) = blocks(&s2);
+        let b_tt =
+            dd2(rr1, tt2) + dd2(tr1.transpose(), tr2) + dd2(tr1, tr2.transpose()) + dd2(tt1, rr2);
+        let b_tr = dd2(rr1, tr2.transpose()) + dd2(tr1.transpose(), rr2);
+        let b = mat6_from_blocks(b_tt, b_tr, b_tr.transpose(), dd2(rr1, rr2));
+        let a1s2 = mul(&a1, &s2);
+        let a2s1 = mul(&a2, &s1);
+        let mut covariance = s1;
+        for i in 0..6 {
+            for j in 0..6 {
+                covariance[i][j] += s2[i][j]
+                    + (a1s2[i][j] + a1s2[j][i] + a2s1[i][j] + a2s1[j][i]) / 12.0
+                    + b[i][j] / 4.0;
+            }
+        }
+        UncertainRbt {
+            mean: &self.mean * &rhs.mean,
+            covariance,
+        }
+    }
+
+    /// Returns `S` with `S * Sᵀ == covariance`, tolerating singular covariances.
+    fn sqrt_covariance(&self) -> [[f32; 6]; 6] {
+        let (values, vectors) =
+            linalg::symmetric_eigen(&self.covariance.map(|row| row.map(|x| x as f64)));
+        let mut s = [[0.0; 6]; 6];
+        for (row, v) in s.iter_mut().zip(&vectors) {
+            for ((x, v), l) in row.iter_mut().zip(v).zip(&values) {
+                *x = (v * l.max(0.0).sqrt()) as f32;
+            }
+        }
+        s
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// An error converting a row major covariance that doesn't have 36 entries.
+pub struct CovarianceLenError {
+    /// The number of entries found.
+    pub len: usize,
+}
+
+impl fmt::Display for CovarianceLenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "covariance has {} entries, expected 36", self.len)
+    }
+}
+
+impl std::error::Error for CovarianceLenError {}
+
+/// Propagates uncertain poses through `f` by sampling.
+///
+/// Draws `samples` independent sets of poses from `inputs`, maps each set through `f` and fits the results.
+/// Returns `None` if `samples` is less than 2.
+pub fn monte_carlo(
+    inputs: &[UncertainRbt],
+    samples: usize,
+    seed: u64,
+    f: impl Fn(&[Rbt]) -> Rbt,
+) -> Option<UncertainRbt> {
+    let mut rng = Rng::new(seed);
+    let roots: Vec<[[f32; 6]; 6]> = inputs.iter().map(|i| i.sqrt_covariance()).collect();
+    let mut drawn = Vec::with_capacity(inputs.len());
+    let outputs: Vec<Rbt> = (0..samples)
+        .map(|_| {
+            drawn.clear();
+            for (input, root) in inputs.iter().zip(&roots) {
+                let z = [(); 6].map(|_| rng.gaussian());
+                let delta = root.map(|row| row.iter().zip(&z).map(|(a, b)| a * b).sum());
+                drawn.push(&input.mean * Rbt::exp(&Twist::from_array(delta)));
+            }
+            f(&drawn)
+        })
+        .collect();
+    UncertainRbt::fit(&outputs)
+}
+
+/// Returns `Ad(rbt) * covariance * Ad(rbt)ᵀ`.
+pub(crate) fn transport(covariance: &Mat6, rbt: &Rbt) -> Mat6 {
+    let ad = rbt.adjoint();
+    let mut ad_t = ad;
+    for (i, row) in ad_t.iter_mut().enumerate() {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = ad[j][i];
+        }
+    }
+    mul(&mul(&ad, covariance), &ad_t)
+}
+
+fn mul(a: &Mat6, b: &Mat6) -> Mat6 {
+    let mut m = [[0.0; 6]; 6];
+    for (row, a) in m.iter_mut().zip(a) {
+        for (j, x) in row.iter_mut().enumerate() {
+            *x = (0..6).map(|k| a[k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+fn add(a: &Mat6, b: &Mat6) -> Mat6 {
+    let mut m = *a;
+    for (row, b) in m.iter_mut().zip(b) {
+        row.iter_mut().zip(b).for_each(|(x, b)| *x += b);
+    }
+    m
+}
+
+/// Returns the translation-translation, translation-rotation and rotation-rotation blocks.
+fn blocks(m: &Mat6) -> (Mat3, Mat3, Mat3) {
+    let col = |r: usize, c: usize| Vec3::new(m[r][c], m[r + 1][c], m[r + 2][c]);
+    let block = |r: usize, c: usize| Mat3::from_cols(col(r, c), col(r, c + 1), col(r, c + 2));
+    (block(0, 0), block(0, 3), block(3, 3))
+}
+
+/// Returns `⟨⟨a⟩⟩ = -tr(a) I + a`.
+fn dd(a: Mat3) -> Mat3 {
+    a - Mat3::from_diagonal(Vec3::splat(a.x_axis.x + a.y_axis.y + a.z_axis.z))
+}
+
+/// Returns `⟨⟨a, b⟩⟩ = ⟨⟨a⟩⟩⟨⟨b⟩⟩ + ⟨⟨ba⟩⟩`.
+fn dd2(a: Mat3, b: Mat3) -> Mat3 {
+    dd(a) * dd(b) + dd(b * a)
+}
+
+/// Returns the matrix of the second order terms, `[[⟨⟨Σrr⟩⟩, ⟨⟨Σtr + Σtrᵀ⟩⟩], [0, ⟨⟨Σrr⟩⟩]]`.
+fn quadratic(covariance: &Mat6) -> Mat6 {
+    let (_, tr, rr) = blocks(covariance);
+    mat6_from_blocks(dd(rr), dd(tr + tr.transpose()), Mat3::ZERO, dd(rr))
+}
@@ -0,0 +1,33 @@
+//! Translations as `uom` lengths.
+//!
+//! The untyped translation of a `Rbt` is taken to be in meters.
+
+use crate::{Quat, Rbt, Vec3};
+use uom::si::f32::Length;
+use uom::si::length::meter;
+
+fn to_vec3(translation: [Length; 3]) -> Vec3 {
+    Vec3::from(translation.map(|l| l.get::<meter>()))
+}
+
+impl Rbt {
+    /// Creates a Rbt from translation lengths with identity rotation.
+    pub fn from_t_length(translation: [Length; 3]) -> Self {
+        Rbt::from_t(to_vec3(translation))
+    }
+
+    /// Creates a Rbt from translation lengths and rotation.
+    pub fn from_t_r_length(translation: [Length; 3], rotation: Quat) -> Self {
+        Rbt::from_t_r(to_vec3(translation), rotation)
+    }
+
+    /// Returns the translation as lengths.
+    pub fn t_length(&self) -> [Length; 3] {
+        self.translation.to_array().map(Length::new::<meter>)
+    }
+
+    /// Sets the translation from lengths.
+    pub fn set_t_length(&mut self, translation: [Length; 3]) {
+        self.translation = to_vec3(translation);
+    }
+}
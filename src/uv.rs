@@ -0,0 +1,104 @@
+//! Conversions to and from `ultraviolet`.
+//!
+//! A `Rotor3` with scalar `s` and bivector `(xy, xz, yz)` is the quaternion `(-yz, xz, -xy, s)`,
+//! following `Rotor3::into_quaternion_array`. Both rotate vectors the same way.
+
+use crate::{Quat, Rbt, Vec3};
+use ::ultraviolet as uv;
+
+/// Converts a quaternion to a rotor.
+pub fn quat_to_rotor3(q: Quat) -> uv::Rotor3 {
+    uv::Rotor3::from_quaternion_array(q.to_array())
+}
+
+/// Converts a rotor to a quaternion.
+pub fn rotor3_to_quat(r: uv::Rotor3) -> Quat {
+    Quat::from_array(r.into_quaternion_array())
+}
+
+impl From<&Rbt> for uv::Isometry3 {
+    fn from(rbt: &Rbt) -> Self {
+        let t = rbt.translation;
+        uv::Isometry3::new(uv::Vec3::new(t.x, t.y, t.z), quat_to_rotor3(rbt.rotation))
+    }
+}
+
+impl From<&uv::Isometry3> for Rbt {
+    fn from(iso: &uv::Isometry3) -> Self {
+        let t = iso.translation;
+        Rbt::from_t_r(Vec3::new(t.x, t.y, t.z), rotor3_to_quat(iso.rotation))
+    }
+}
+
+macro_rules! wide_interop {
+    (
+        $n:literal, $f:ident, $vec3:ident, $bivec:ident, $rotor:ident, $iso:ident,
+        $to_rotor:ident, $from_rotor:ident, $to_iso:ident, $from_iso:ident
+    ) => {
+        #[doc = concat!("Converts ", $n, " quaternions to a `", stringify!($rotor), "`, one per lane.")]
+        pub fn $to_rotor(qs: &[Quat; $n]) -> uv::$rotor {
+            let lane = |f: fn(&Quat) -> f32| uv::$f::new(std::array::from_fn(|i| f(&qs[i])));
+            uv::$rotor::new(
+                lane(|q| q.w),
+                uv::$bivec::new(lane(|q| -q.z), lane(|q| q.y), lane(|q| -q.x)),
+            )
+        }
+
+        #[doc = concat!("Converts a `", stringify!($rotor), "` to ", $n, " quaternions, one per lane.")]
+        pub fn $from_rotor(r: &uv::$rotor) -> [Quat; $n] {
+            let (s, xy, xz, yz) = (
+                r.s.to_array(),
+                r.bv.xy.to_array(),
+                r.bv.xz.to_array(),
+                r.bv.yz.to_array(),
+            );
+            std::array::from_fn(|i| Quat::from_xyzw(-yz[i], xz[i], -xy[i], s[i]))
+        }
+
+        #[doc = concat!("Converts ", $n, " transforms to a `", stringify!($iso), "`, one per lane.")]
+        pub fn $to_iso(rbts: &[Rbt; $n]) -> uv::$iso {
+            let lane = |f: fn(&Rbt) -> f32| uv::$f::new(std::array::from_fn(|i| f(&rbts[i])));
+            uv::$iso::new(
+                uv::$vec3::new(
+                    lane(|r| r.translation.x),
+                    lane(|r| r.translation.y),
+                    lane(|r| r.translation.z),
+                ),
+                $to_rotor(&std::array::from_fn(|i| rbts[i].rotation)),
+            )
+        }
+
+        #[doc = concat!("Converts a `", stringify!($iso), "` to ", $n, " transforms, one per lane.")]
+        pub fn $from_iso(iso: &uv::$iso) -> [Rbt; $n] {
+            let t = iso.translation;
+            let (x, y, z) = (t.x.to_array(), t.y.to_array(), t.z.to_array());
+            let r = $from_rotor(&iso.rotation);
+            std::array::from_fn(|i| Rbt::from_t_r(Vec3::new(x[i], y[i], z[i]), r[i]))
+        }
+    };
+}
+
+wide_interop!(
+    4,
+    f32x4,
+    Vec3x4,
+    Bivec3x4,
+    Rotor3x4,
+    Isometry3x4,
+    quats_to_rotor3x4,
+    rotor3x4_to_quats,
+    rbts_to_isometry3x4,
+    isometry3x4_to_rbts
+);
+wide_interop!(
+    8,
+    f32x8,
+    Vec3x8,
+    Bivec3x8,
+    Rotor3x8,
+    Isometry3x8,
+    quats_to_rotor3x8,
+    rotor3x8_to_quats,
+    rbts_to_isometry3x8,
+    isometry3x8_to_rbts
+);
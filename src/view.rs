@@ -0,0 +1,154 @@
+//! Poses viewed in place in float buffers.
+//!
+//! A pose is 7 floats `tx, ty, tz, qx, qy, qz, qw`, optionally followed by 1 float of padding,
+//! e.g. records of a memory mapped log.
+
+use crate::{Quat, Rbt, Vec3};
+use core::ops::Mul;
+
+fn check_len(len: usize) {
+    assert!(len == 7 || len == 8, "pose view needs 7 or 8 floats");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A pose borrowed from a float buffer, without copying.
+pub struct RbtRef<'a> {
+    data: &'a [f32],
+}
+
+impl<'a> RbtRef<'a> {
+    /// Views `data` as a pose.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` length is not 7 or 8.
+    pub fn new(data: &'a [f32]) -> Self {
+        check_len(data.len());
+        RbtRef { data }
+    }
+
+    /// Views every `stride` floats of `buffer` as a pose, ignoring a trailing partial record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is not 7 or 8.
+    pub fn iter(buffer: &'a [f32], stride: usize) -> impl Iterator<Item = RbtRef<'a>> {
+        check_len(stride);
+        buffer.chunks_exact(stride).map(|data| RbtRef { data })
+    }
+
+    /// Returns the viewed floats.
+    pub fn as_slice(&self) -> &'a [f32] {
+        self.data
+    }
+
+    /// Returns the translation part.
+    pub fn translation(&self) -> Vec3 {
+        Vec3::from_slice(&self.data[0..3])
+    }
+
+    /// Returns the rotation part.
+    pub fn rotation(&self) -> Quat {
+        Quat::from_slice(&self.data[3..7])
+    }
+
+    /// Copies into an owned `Rbt`.
+    pub fn to_rbt(&self) -> Rbt {
+        Rbt::from_t_r(self.translation(), self.rotation())
+    }
+
+    /// Transforms `point`.
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.rotation() * point + self.translation()
+    }
+
+    /// Transforms `vector`, ignoring translation.
+    pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+        self.rotation() * vector
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// A pose mutably borrowed from a float buffer, without copying.
+pub struct RbtMut<'a> {
+    data: &'a mut [f32],
+}
+
+impl<'a> RbtMut<'a> {
+    /// Views `data` as a pose.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` length is not 7 or 8.
+    pub fn new(data: &'a mut [f32]) -> Self {
+        check_len(data.len());
+        RbtMut { data }
+    }
+
+    /// Views every `stride` floats of `buffer` as a pose, ignoring a trailing partial record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is not 7 or 8.
+    pub fn iter(buffer: &'a mut [f32], stride: usize) -> impl Iterator<Item = RbtMut<'a>> {
+        check_len(stride);
+        buffer.chunks_exact_mut(stride).map(|data| RbtMut { data })
+    }
+
+    /// Returns an immutable view.
+    pub fn as_ref(&self) -> RbtRef<'_> {
+        RbtRef { data: self.data }
+    }
+
+    /// Sets the translation part.
+    pub fn set_translation(&mut self, translation: Vec3) {
+        translation.write_to_slice(&mut self.data[0..3]);
+    }
+
+    /// Sets the rotation part.
+    pub fn set_rotation(&mut self, rotation: Quat) {
+        rotation.write_to_slice(&mut self.data[3..7]);
+    }
+
+    /// Overwrites with `rbt`.
+    pub fn set(&mut self, rbt: &Rbt) {
+        self.set_translation(rbt.translation);
+        self.set_rotation(rbt.rotation);
+    }
+
+    /// Replaces the pose `T` with `lhs * T`.
+    pub fn left_multiply(&mut self, lhs: &Rbt) {
+        let rbt = lhs * self.as_ref();
+        self.set(&rbt);
+    }
+
+    /// Replaces the pose `T` with `T * rhs`.
+    pub fn right_multiply(&mut self, rhs: &Rbt) {
+        let rbt = self.as_ref() * rhs;
+        self.set(&rbt);
+    }
+}
+
+impl Mul<RbtRef<'_>> for RbtRef<'_> {
+    type Output = Rbt;
+
+    fn mul(self, rhs: RbtRef<'_>) -> Rbt {
+        self.to_rbt() * rhs.to_rbt()
+    }
+}
+
+impl Mul<&Rbt> for RbtRef<'_> {
+    type Output = Rbt;
+
+    fn mul(self, rhs: &Rbt) -> Rbt {
+        self.to_rbt() * rhs
+    }
+}
+
+impl Mul<RbtRef<'_>> for &Rbt {
+    type Output = Rbt;
+
+    fn mul(self, rhs: RbtRef<'_>) -> Rbt {
+        self * rhs.to_rbt()
+    }
+}
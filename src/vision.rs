@@ -0,0 +1,55 @@
+//! Interop with computer vision conventions.
+
+use crate::lie::{skew, so3_exp, so3_log};
+use crate::{Mat3, Quat, Rbt, Vec3};
+
+/// The rotation between computer vision camera axes (+Y down, +Z forward) and graphics camera axes (+Y up, -Z forward).
+///
+/// π around X, its own inverse.
+const CV_TO_GL_CAMERA: Quat = Quat::from_xyzw(1.0, 0.0, 0.0, 0.0);
+
+impl Rbt {
+    /// Creates a Rbt from an OpenCV rotation vector and translation, e.g. the output of `solvePnP`.
+    ///
+    /// `rvec` is axis times angle, as in `cv::Rodrigues`. Angles above π are fine and wrap around.
+    /// The result maps object coordinates to camera coordinates, like the OpenCV pair.
+    pub fn from_rvec_tvec(rvec: Vec3, tvec: Vec3) -> Self {
+        Rbt::from_t_r(tvec, so3_exp(rvec))
+    }
+
+    /// Converts to an OpenCV rotation vector and translation, as `(rvec, tvec)`.
+    ///
+    /// The angle of `rvec` is in `[0, π]`, as `cv::Rodrigues` returns.
+    pub fn to_rvec_tvec(&self) -> (Vec3, Vec3) {
+        (so3_log(self.rotation), self.translation)
+    }
+
+    /// Converts computer vision extrinsics to a graphics camera pose.
+    ///
+    /// `self` maps world coordinates to camera coordinates with +X right, +Y down and +Z forward, as OpenCV does.
+    /// The result maps camera coordinates with +X right, +Y up and -Z forward to world coordinates, as OpenGL cameras do.
+    pub fn cv_extrinsics_to_gl_pose(&self) -> Rbt {
+        let inverse = self.inverse();
+        Rbt::from_t_r(
+            inverse.translation,
+            (inverse.rotation * CV_TO_GL_CAMERA).normalize(),
+        )
+    }
+
+    /// Converts a graphics camera pose to computer vision extrinsics, reversing `cv_extrinsics_to_gl_pose`.
+    pub fn gl_pose_to_cv_extrinsics(&self) -> Rbt {
+        Rbt::from_t_r(
+            self.translation,
+            (self.rotation * CV_TO_GL_CAMERA).normalize(),
+        )
+        .inverse()
+    }
+
+    /// Returns the essential matrix `E = [t]× R`, with `self` mapping camera 1 coordinates to camera 2 coordinates.
+    ///
+    /// Normalized image points `x1` and `x2` of the same scene point satisfy `x2ᵀ E x1 == 0`.
+    /// `lie::skew` builds `[t]×`.
+    pub fn essential_matrix(&self) -> Mat3 {
+        skew(self.translation) * Mat3::from_quat(self.rotation)
+    }
+}
@@ -0,0 +1,145 @@
+//! Poses in large worlds.
+
+use crate::{DVec3, Quat, Rbt};
+use std::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A rigid body transform with `f64` translation, for worlds too large for `f32` precision.
+pub struct WorldRbt {
+    /// The translation part.
+    pub translation: DVec3,
+    /// The rotation part.
+    pub rotation: Quat,
+}
+
+impl WorldRbt {
+    /// Creates a WorldRbt from translation and rotation.
+    pub fn from_t_r(translation: DVec3, rotation: Quat) -> Self {
+        WorldRbt {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Converts from a `Rbt`.
+    pub fn from_rbt(rbt: &Rbt) -> Self {
+        WorldRbt {
+            translation: rbt.translation.as_dvec3(),
+            rotation: rbt.rotation,
+        }
+    }
+
+    /// Converts to a `Rbt`, losing translation precision far from the origin.
+    pub fn to_rbt(&self) -> Rbt {
+        Rbt::from_t_r(self.translation.as_vec3(), self.rotation)
+    }
+
+    /// Returns the inverse of `self`.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        WorldRbt {
+            translation: -(rotation.as_dquat() * self.translation),
+            rotation,
+        }
+    }
+
+    /// Returns `self` relative to `origin`, `origin⁻¹ * self`, in `f32`.
+    ///
+    /// Precise as long as `self` is near `origin`.
+    pub fn relative_to(&self, origin: &WorldRbt) -> Rbt {
+        let inv_r = origin.rotation.conjugate();
+        Rbt::from_t_r(
+            (inv_r.as_dquat() * (self.translation - origin.translation)).as_vec3(),
+            (inv_r * self.rotation).normalize(),
+        )
+    }
+
+    /// Transforms `point`.
+    pub fn transform_point(&self, point: DVec3) -> DVec3 {
+        self.rotation.as_dquat() * point + self.translation
+    }
+}
+
+impl Mul<&Rbt> for &WorldRbt {
+    type Output = WorldRbt;
+
+    fn mul(self, rhs: &Rbt) -> WorldRbt {
+        WorldRbt {
+            translation: self.translation + (self.rotation * rhs.translation).as_dvec3(),
+            rotation: (self.rotation * rhs.rotation).normalize(),
+        }
+    }
+}
+
+impl Mul<Rbt> for &WorldRbt {
+    type Output = WorldRbt;
+
+    fn mul(self, rhs: Rbt) -> WorldRbt {
+        self * &rhs
+    }
+}
+
+impl Mul<&Rbt> for WorldRbt {
+    type Output = WorldRbt;
+
+    fn mul(self, rhs: &Rbt) -> WorldRbt {
+        &self * rhs
+    }
+}
+
+impl Mul<Rbt> for WorldRbt {
+    type Output = WorldRbt;
+
+    fn mul(self, rhs: Rbt) -> WorldRbt {
+        &self * &rhs
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// A movable origin that world poses are expressed relative to in `f32`, e.g. the camera position.
+///
+/// Only translates: local axes are parallel to world axes.
+pub struct FloatingOrigin {
+    /// The world position of the origin.
+    pub origin: DVec3,
+}
+
+impl FloatingOrigin {
+    /// Creates an origin at `origin`.
+    pub fn new(origin: DVec3) -> Self {
+        FloatingOrigin { origin }
+    }
+
+    /// Returns `pose` relative to the origin.
+    pub fn to_local(&self, pose: &WorldRbt) -> Rbt {
+        Rbt::from_t_r((pose.translation - self.origin).as_vec3(), pose.rotation)
+    }
+
+    /// Returns the world pose of `local`, given relative to the origin.
+    pub fn to_world(&self, local: &Rbt) -> WorldRbt {
+        WorldRbt::from_t_r(self.origin + local.translation.as_dvec3(), local.rotation)
+    }
+
+    /// Writes `poses` relative to the origin to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ.
+    pub fn to_local_all(&self, poses: &[WorldRbt], out: &mut [Rbt]) {
+        assert_eq!(poses.len(), out.len(), "length mismatch");
+        for (pose, out) in poses.iter().zip(out) {
+            *out = self.to_local(pose);
+        }
+    }
+
+    /// Moves the origin to `origin`, re-expressing `locals` relative to it.
+    ///
+    /// Shifting loses precision if the origin jumps far. Then recompute `locals` with `to_local_all` instead.
+    pub fn rebase(&mut self, origin: DVec3, locals: &mut [Rbt]) {
+        let shift = (self.origin - origin).as_vec3();
+        for local in locals {
+            local.translation += shift;
+        }
+        self.origin = origin;
+    }
+}